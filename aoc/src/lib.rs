@@ -1,115 +1,222 @@
 use proc_macro::{self, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
-    braced, bracketed, parse_macro_input, punctuated::Punctuated, token::Bracket, Expr, ExprCall,
-    Ident, LitInt, Token,
+    parse::Parser, parse_macro_input, punctuated::Punctuated, spanned::Spanned, Expr, Item, Lit,
+    Meta, Token,
 };
 
-enum RangeOrArray {
-    ItemArray(Punctuated<LitInt, Token![,]>),
-    Range(u8, u8),
+/// Arguments of the `#[aoc(...)]` attribute
+#[derive(Default)]
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    name: Option<String>,
+    part1: Option<String>,
+    part2: Option<String>,
+    generator: bool,
 }
 
-struct Input {
-    match_expr: Expr,
-    call_expr: ExprCall,
-    format_prefix: Ident,
-    format_spec: usize,
-    items: RangeOrArray,
+impl Args {
+    fn parse(attr: TokenStream) -> syn::Result<Self> {
+        let mut args = Args::default();
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated.parse(attr)?;
+        for meta in metas {
+            let pair = match meta {
+                Meta::Path(path) if path.is_ident("generator") => {
+                    args.generator = true;
+                    continue;
+                }
+                Meta::NameValue(pair) => pair,
+                meta => return Err(syn::Error::new(meta.span(), "expected `key = value`")),
+            };
+            let Expr::Lit(lit) = &pair.value else {
+                return Err(syn::Error::new(pair.value.span(), "expected a literal"));
+            };
+            let key = pair.path.to_token_stream().to_string();
+            match (key.as_str(), &lit.lit) {
+                ("year", Lit::Int(year)) => args.year = Some(year.base10_parse()?),
+                ("day", Lit::Int(day)) => args.day = Some(day.base10_parse()?),
+                ("part", Lit::Int(part)) => args.part = Some(part.base10_parse()?),
+                ("name", Lit::Str(name)) => args.name = Some(name.value()),
+                ("part1" | "part2", lit) => {
+                    let answer = match lit {
+                        Lit::Int(answer) => answer.base10_digits().to_string(),
+                        Lit::Str(answer) => answer.value(),
+                        _ => return Err(syn::Error::new(lit.span(), "expected an answer")),
+                    };
+                    match key.as_str() {
+                        "part1" => args.part1 = Some(answer),
+                        _ => args.part2 = Some(answer),
+                    }
+                }
+                _ => return Err(syn::Error::new(
+                    pair.span(),
+                    "expected `year = <int>`, `day = <int>`, `part = <int>`, `name = \"...\"`, \
+                     `part1 = <answer>`, `part2 = <answer>` or `generator`",
+                )),
+            }
+        }
+        Ok(args)
+    }
 }
 
-impl syn::parse::Parse for Input {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let match_expr = input.parse()?;
-        let _separator = input.parse::<Token![=>]>()?;
-        let format_prefix = input.parse::<Ident>()?;
-
-        let braced_content;
-        braced!(braced_content in input);
-        braced_content.parse::<Token![:]>()?;
-
-        let format_spec = braced_content.parse::<LitInt>()?.base10_parse()?;
-        input.parse::<Token![::]>()?;
-
-        let call_expr = input.parse()?;
-        let _separator = input.parse::<Token![,]>()?;
-
-        let la = input.lookahead1();
-        let items = if la.peek(syn::Lit) {
-            let start = input.parse::<syn::LitInt>()?;
-            let _delim = input.parse::<Token![..]>()?;
-            let end = input.parse::<syn::LitInt>()?;
-            RangeOrArray::Range(start.base10_parse()?, end.base10_parse()?)
-        } else if la.peek(Bracket) {
-            let items;
-            bracketed!(items in input);
-            let item_array = items.parse_terminated(LitInt::parse, Token![,])?;
-            RangeOrArray::ItemArray(item_array)
-        } else {
-            return Err(la.error());
-        };
-
-        Ok(Input {
-            match_expr,
-            call_expr,
-            format_prefix,
-            format_spec,
-            items,
-        })
+fn part_variant(part: u8, span: proc_macro2::Span) -> syn::Result<proc_macro2::TokenStream> {
+    match part {
+        1 => Ok(quote! { crate::runner::Part::One }),
+        2 => Ok(quote! { crate::runner::Part::Two }),
+        _ => Err(syn::Error::new(span, "part must be 1 or 2")),
     }
 }
 
-#[proc_macro]
-pub fn aoc(items: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(items as Input);
+/// Registers one part of a day so that the runner can find it. `solver` is the
+/// type implementing `Solution` whose `parse` provides the input for `solve`.
+fn registration(
+    year: u16,
+    day: u8,
+    part: proc_macro2::TokenStream,
+    name: &str,
+    primary: bool,
+    solver: proc_macro2::TokenStream,
+    solve: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        ::inventory::submit! {
+            crate::registry::Implementation {
+                year: #year,
+                day: #day,
+                part: #part,
+                name: #name,
+                primary: #primary,
+                run: |input| crate::registry::run::<#solver, _>(input, #solve),
+                bench_parse: crate::bench::measure_parse::<#solver>,
+                bench: |input, budget| crate::bench::measure::<#solver, _>(input, budget, #solve),
+            }
+        }
+    }
+}
 
-    let match_expr = input.match_expr;
+fn expand(args: Args, item: Item) -> syn::Result<proc_macro2::TokenStream> {
+    let span = proc_macro2::Span::call_site();
+    let (Some(year), Some(day)) = (args.year, args.day) else {
+        return Err(syn::Error::new(span, "expected both `year` and `day`"));
+    };
 
-    let call = input.call_expr;
-    let arms = match input.items {
-        RangeOrArray::Range(start, end) => {
-            let elems = start..=end;
-            let expanded = quote! {
-                [ #( #elems ),* ]
+    let registrations = match &item {
+        Item::Impl(item_impl) => {
+            let solver = item_impl.self_ty.to_token_stream();
+            let name = args.name.unwrap_or_else(|| "default".to_string());
+            let parts = match args.part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
             };
-
-            let prefix = input.format_prefix;
-            let spec = input.format_spec;
-            let expr = quote! {
-                #match_expr => #prefix{:#spec}::#call, #expanded
+            parts
+                .into_iter()
+                .map(|part| {
+                    let solve = match part {
+                        1 => quote! { <#solver as crate::solutions::Solution>::part1 },
+                        _ => quote! { <#solver as crate::solutions::Solution>::part2 },
+                    };
+                    Ok(registration(
+                        year,
+                        day,
+                        part_variant(part, span)?,
+                        &name,
+                        true,
+                        solver.clone(),
+                        solve,
+                    ))
+                })
+                .collect::<syn::Result<Vec<_>>>()?
+        }
+        Item::Fn(item_fn) if args.generator => {
+            let ident = &item_fn.sig.ident;
+            vec![quote! {
+                ::inventory::submit! {
+                    crate::registry::Generator {
+                        year: #year,
+                        day: #day,
+                        generate: #ident,
+                    }
+                }
+            }]
+        }
+        Item::Fn(item_fn) => {
+            let Some(part) = args.part else {
+                return Err(syn::Error::new(
+                    span,
+                    "expected `part` when registering a function",
+                ));
             };
-
-            return aoc(expr.into());
+            let ident = &item_fn.sig.ident;
+            let name = args.name.unwrap_or_else(|| ident.to_string());
+            vec![registration(
+                year,
+                day,
+                part_variant(part, span)?,
+                &name,
+                false,
+                quote! { Solver },
+                quote! { |parsed| #ident(parsed) },
+            )]
         }
-        RangeOrArray::ItemArray(item_array) => item_array
-            .iter()
-            .map(|elem| {
-                let (elem_str, ident) = (
-                    syn::LitInt::new(elem.base10_digits(), elem.span()),
-                    proc_macro2::Ident::new(
-                        &format!(
-                            "{}{:0>width$}",
-                            input.format_prefix,
-                            elem.base10_parse::<usize>().unwrap(),
-                            width = input.format_spec
-                        ),
-                        input.format_prefix.span(),
-                    ),
-                );
-                quote! {
-                    #elem_str => #ident::#call
+        Item::Const(item_const) => {
+            let ident = &item_const.ident;
+            let expected = |answer: Option<String>| match answer {
+                Some(answer) => quote! { Some(#answer) },
+                None => quote! { None },
+            };
+            let (part1, part2) = (expected(args.part1), expected(args.part2));
+            vec![quote! {
+                ::inventory::submit! {
+                    crate::registry::Sample {
+                        year: #year,
+                        day: #day,
+                        input: #ident,
+                        part1: #part1,
+                        part2: #part2,
+                    }
                 }
-                .clone()
-            })
-            .collect::<Vec<_>>(),
-    };
-
-    let tokens = quote! {
-        match(#match_expr) {
-            #(#arms,)*
-            _ => unimplemented!()
+            }]
+        }
+        _ => {
+            return Err(syn::Error::new(
+                item.span(),
+                "expected an `impl Solution` block, a function or a sample",
+            ))
         }
     };
 
-    TokenStream::from(tokens)
+    Ok(quote! {
+        #item
+        #( #registrations )*
+    })
+}
+
+/// Registers solutions with the runner.
+///
+/// On an `impl Solution for Solver` block, e.g. `#[aoc(year = 2023, day = 7)]`,
+/// both parts are registered as the default implementation of that day.
+///
+/// On a function, e.g. `#[aoc(year = 2023, day = 7, part = 2)]`, the function
+/// is registered as an alternative implementation of that part, named after the
+/// function unless `name = "..."` is given. It gets called with the input as
+/// parsed by the `Solver` of the module it is defined in.
+///
+/// On a function with the `generator` flag, e.g.
+/// `#[aoc(year = 2023, day = 7, generator)]`, the function is registered as the
+/// generator of random inputs for that day. It gets called with a seeded random
+/// number generator and the size of the input to generate.
+///
+/// On a `&str` constant, e.g. `#[aoc(year = 2023, day = 7, part1 = 6440)]`, the
+/// constant is registered as a sample input of that day, together with the
+/// answers it should produce for the parts given.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as Item);
+    let result = Args::parse(attr).and_then(|args| expand(args, item));
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
 }
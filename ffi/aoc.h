@@ -0,0 +1,50 @@
+/* Generated from src/ffi.rs, see there for details. Do not edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Every `day` is a day of 2023 */
+#define AOC_YEAR 2023
+
+/* The answer was written to `out_buf` */
+#define AOC_OK 0
+/* A required pointer is NULL */
+#define AOC_ERR_NULL_POINTER 1
+/* There is no solution for this day and part */
+#define AOC_ERR_NO_SOLUTION 2
+/* The input isn't valid UTF-8 */
+#define AOC_ERR_INVALID_UTF8 3
+/* The input is invalid, `out_buf` describes why */
+#define AOC_ERR_INVALID_INPUT 4
+/* The solution panicked, `out_buf` has the message */
+#define AOC_ERR_PANICKED 5
+/* `out_buf` needs `*out_len + 1` bytes */
+#define AOC_ERR_BUFFER_TOO_SMALL 6
+/* There is no sample for this day and part */
+#define AOC_ERR_NO_SAMPLE 7
+
+/*
+ * `*out_len` is the size of `out_buf` when called, and the length of the text
+ * written to it, without the terminating NUL, on return.
+ */
+
+/* Solves `part` of `day` for the input in `input_ptr` and writes the answer to `out_buf`. */
+int aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t input_len, uint8_t *out_buf, size_t *out_len);
+
+/* Writes the sample for `part` of `day` to `out_buf`. */
+int aoc_sample_input(uint8_t day, uint8_t part, uint8_t *out_buf, size_t *out_len);
+
+/* Writes the answer to the sample for `part` of `day` to `out_buf`. */
+int aoc_sample_answer(uint8_t day, uint8_t part, uint8_t *out_buf, size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,55 @@
+/*
+ * Solves the sample of every day through the C interface and compares the
+ * answers with the expected ones. Build the library first, then run:
+ *
+ *   cc ffi/test_aoc.c -Iffi -Ltarget/debug -ladvent_of_code_2023 -o target/test_aoc
+ *   LD_LIBRARY_PATH=target/debug target/test_aoc
+ */
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static uint8_t input[1 << 16];
+static uint8_t expected[256];
+static uint8_t answer[256];
+
+int main(void) {
+    int failures = 0;
+    int samples = 0;
+    for (uint8_t day = 1; day <= 25; day++) {
+        for (uint8_t part = 1; part <= 2; part++) {
+            size_t input_len = sizeof(input);
+            if (aoc_sample_input(day, part, input, &input_len) == AOC_ERR_NO_SAMPLE) {
+                continue;
+            }
+            size_t expected_len = sizeof(expected);
+            aoc_sample_answer(day, part, expected, &expected_len);
+
+            size_t answer_len = sizeof(answer);
+            int code = aoc_solve(day, part, input, input_len, answer, &answer_len);
+            samples++;
+            if (code != AOC_OK || strcmp((char *)answer, (char *)expected) != 0) {
+                printf("day %d part %d: expected %s, got %s (code %d)\n", day, part,
+                       (char *)expected, code == AOC_OK ? (char *)answer : "nothing", code);
+                failures++;
+            }
+        }
+    }
+
+    size_t len = sizeof(answer);
+    if (aoc_solve(30, 1, input, 0, answer, &len) != AOC_ERR_NO_SOLUTION) {
+        printf("day 30 should have no solution\n");
+        failures++;
+    }
+    len = 1;
+    if (aoc_solve(6, 1, (const uint8_t *)"Time: 7\nDistance: 9\n", 20, answer, &len) !=
+            AOC_ERR_BUFFER_TOO_SMALL ||
+        len != 1) {
+        printf("a buffer of 1 byte should be too small for the answer 4\n");
+        failures++;
+    }
+
+    printf("%d of %d samples passed\n", samples - failures, samples);
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,252 @@
+use std::{
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The result of solving one part of a puzzle.
+///
+/// Values are normalized on construction: non-negative integers are always
+/// stored as `Unsigned` and floats without a fractional part become integers,
+/// so that two answers compare equal whenever they would print the same. Text
+/// is only read as a number if it is written exactly the way that number
+/// prints, so e.g. `007` and `1e3` stay text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+    Text(String),
+}
+
+macro_rules! ImplFromUnsigned {
+    ( $($utype:ty),* ) => {
+        $(impl From<$utype> for Answer {
+            fn from(value: $utype) -> Self {
+                Answer::Unsigned(value as u128)
+            }
+        })*
+    };
+}
+
+macro_rules! ImplFromSigned {
+    ( $($itype:ty),* ) => {
+        $(impl From<$itype> for Answer {
+            fn from(value: $itype) -> Self {
+                Answer::from(value as i128)
+            }
+        })*
+    };
+}
+
+ImplFromUnsigned!(u8, u16, u32, u64, u128, usize);
+ImplFromSigned!(i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match u128::try_from(value) {
+            Ok(unsigned) => Answer::Unsigned(unsigned),
+            Err(_) => Answer::Signed(value),
+        }
+    }
+}
+
+impl From<f64> for Answer {
+    fn from(value: f64) -> Self {
+        let integral = value.is_finite()
+            && value.fract() == 0.0
+            && (i128::MIN as f64..=i128::MAX as f64).contains(&value);
+        if integral {
+            Answer::from(value as i128)
+        } else {
+            Answer::Float(value)
+        }
+    }
+}
+
+impl From<f32> for Answer {
+    fn from(value: f32) -> Self {
+        Answer::from(value as f64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        value.parse().unwrap()
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        value.parse().unwrap()
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let number = if let Ok(unsigned) = s.parse::<u128>() {
+            Some(Answer::Unsigned(unsigned))
+        } else if let Ok(signed) = s.parse::<i128>() {
+            Some(Answer::from(signed))
+        } else {
+            // `NaN` would never compare equal to the same answer
+            let float = s.parse::<f64>().ok().filter(|float| float.is_finite());
+            float.map(Answer::from)
+        };
+        // Anything else, like leading zeros, wouldn't survive the conversion
+        match number {
+            Some(number) if number.to_string() == s => Ok(number),
+            _ => Ok(Answer::Text(s.to_string())),
+        }
+    }
+}
+
+impl Answer {
+    /// Name of the type of answer, as used in machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Float(_) => "float",
+            Answer::Text(_) => "text",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Float(value) => write!(f, "{value}"),
+            Answer::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Numbers are ordered by value, text only relative to other text
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        use Answer::*;
+        match (self, other) {
+            (Unsigned(a), Unsigned(b)) => a.partial_cmp(b),
+            (Signed(a), Signed(b)) => a.partial_cmp(b),
+            // Signed answers are always negative, see `From<i128>`
+            (Signed(_), Unsigned(_)) => Some(Ordering::Less),
+            (Unsigned(_), Signed(_)) => Some(Ordering::Greater),
+            (Float(a), Unsigned(b)) => a.partial_cmp(&(*b as f64)),
+            (Float(a), Signed(b)) => a.partial_cmp(&(*b as f64)),
+            (Float(a), Float(b)) => a.partial_cmp(b),
+            (Unsigned(_) | Signed(_), Float(_)) => other.partial_cmp(self).map(Ordering::reverse),
+            (Text(a), Text(b)) => a.partial_cmp(b),
+            (Text(_), _) | (_, Text(_)) => None,
+        }
+    }
+}
+
+// Answers are stored as strings, because neither TOML nor JSON can represent
+// all 128 bit integers losslessly
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl de::Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a number or a string")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Answer, E> {
+                Ok(Answer::from(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Answer, E> {
+                Ok(Answer::from(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Answer, E> {
+                Ok(Answer::from(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Answer, E> {
+                Ok(Answer::from(v))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Answer::from(5u8), Answer::from(5i64));
+        assert_eq!(Answer::from(5i64), Answer::Unsigned(5));
+        assert_eq!(Answer::from(-5isize), Answer::Signed(-5));
+        assert_eq!(Answer::from(47.0), Answer::Unsigned(47));
+        assert_eq!(Answer::from(-47.0), Answer::Signed(-47));
+        assert_eq!(Answer::from(0.5), Answer::Float(0.5));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+        assert_eq!(Answer::from(i128::MIN), Answer::Signed(i128::MIN));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("42".parse::<Answer>(), Ok(Answer::Unsigned(42)));
+        assert_eq!(" -42\n".parse::<Answer>(), Ok(Answer::Signed(-42)));
+        assert_eq!("1.25".parse::<Answer>(), Ok(Answer::Float(1.25)));
+        assert_eq!(
+            "EFGHIJK".parse::<Answer>(),
+            Ok(Answer::Text("EFGHIJK".to_string()))
+        );
+        for text in ["007", "1e3", "42.0", "-0", "+5", "NaN", "inf", "-inf"] {
+            assert_eq!(
+                text.parse::<Answer>(),
+                Ok(Answer::Text(text.to_string())),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Answer::from(3u8) < Answer::from(5u8));
+        assert!(Answer::from(-3) < Answer::from(2u8));
+        assert!(Answer::from(-3) > Answer::from(-4));
+        assert!(Answer::from(2.5) > Answer::from(2u8));
+        assert!(Answer::from(-2) < Answer::from(-1.5));
+        assert_eq!(Answer::from("AB").partial_cmp(&Answer::from(5u8)), None);
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for answer in [
+            Answer::from(u128::MAX),
+            Answer::from(i128::MIN),
+            Answer::from(1.25),
+            Answer::from("EFGHIJK"),
+            Answer::from("007"),
+            Answer::from("1e3"),
+            Answer::from("NaN"),
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>(), Ok(answer));
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+
+use crate::{
+    config::{ApplyConfig, Config},
+    output::{format_duration, write_table},
+    runner::{self, DayInput, PartResult, Selection},
+    verify::DayAnswers,
+};
+
+/// Suffix of the file with the known answers for an input, e.g. the answers
+/// for `alice.txt` are in `alice.answers.toml`
+const ANSWERS_SUFFIX: &str = ".answers.toml";
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// Runs a single day on every file in `--input-dir`, which has to be given
+    /// as a flag here. Known answers are read from a file next to each input:
+    /// the ones for `alice.txt` are `part1` and `part2` in `alice.answers.toml`.
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Directory given with `--input-dir`, as opposed to one from the config
+    #[arg(skip)]
+    dir: Option<PathBuf>,
+}
+
+impl ApplyConfig for BatchArgs {
+    fn apply(&mut self, config: &Config) {
+        self.dir = self.selection.input_dir().map(Path::to_path_buf);
+        self.selection.apply(config);
+    }
+}
+
+impl BatchArgs {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.dir.is_none() {
+            return Err("batch needs the directory of inputs as `--input-dir`".to_string());
+        }
+        if self.selection.example() || self.selection.has_input() {
+            return Err("batch only runs the files in `--input-dir`".to_string());
+        }
+        if self.selection.days().len() != 1 {
+            return Err("batch runs a single day, select it with `--day`".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Input files in `dir`, in order, leaving out answer files and hidden files
+fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_file() && !name.starts_with('.') && !name.ends_with(ANSWERS_SUFFIX) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn answers_path(input: &Path) -> PathBuf {
+    let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+    input.with_file_name(format!("{stem}{ANSWERS_SUFFIX}"))
+}
+
+fn load_answers(path: &Path) -> io::Result<Option<DayAnswers>> {
+    match fs::read_to_string(path) {
+        Ok(content) => toml::from_str(&content).map(Some).map_err(|err| {
+            // The full message spans several lines, which doesn't fit in a table
+            let line = err
+                .span()
+                .map_or(1, |span| content[..span.start].matches('\n').count() + 1);
+            let message = format!("line {line}: {}", err.message());
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+const HEADER: [&str; 6] = ["File", "Part", "Answer", "Expected", "Time", "Status"];
+
+/// Compares the results for one file with its known answers
+fn rows(name: &str, results: &[PartResult], answers: Option<&DayAnswers>) -> Vec<[String; 6]> {
+    results
+        .iter()
+        .map(|result| {
+            let expected = answers.and_then(|answers| answers.get(result.part));
+            let status = match (&result.outcome, expected) {
+                (Err(err), _) => format!("ERROR: {err}"),
+                (Ok(timed), Some(expected)) if timed.answer == *expected => "ok".to_string(),
+                (Ok(_), Some(_)) => "MISMATCH".to_string(),
+                (Ok(_), None) => "unknown".to_string(),
+            };
+            [
+                name.to_string(),
+                result.part.to_string(),
+                result.answer().map_or("-".to_string(), |a| a.to_string()),
+                expected.map_or("-".to_string(), |e| e.to_string()),
+                result.elapsed().map_or("-".to_string(), format_duration),
+                status,
+            ]
+        })
+        .collect()
+}
+
+/// Row for a file that couldn't be run at all
+fn error_row(name: &str, err: &str) -> [String; 6] {
+    let mut row: [String; 6] = std::array::from_fn(|_| "-".to_string());
+    row[0] = name.to_string();
+    row[5] = format!("ERROR: {err}");
+    row
+}
+
+pub fn batch(args: BatchArgs) -> bool {
+    let dir = args.dir.as_deref().expect("Checked by validate");
+    let day = args.selection.days()[0];
+    let files = match input_files(dir) {
+        Ok(files) if files.is_empty() => {
+            eprintln!("There are no inputs in {}", dir.display());
+            return false;
+        }
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("Could not read {}: {err}", dir.display());
+            return false;
+        }
+    };
+
+    let mut table = vec![];
+    for path in files {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let answers_path = answers_path(&path);
+        // Still runs the input, to show the answers that the file should have
+        let answers = load_answers(&answers_path).unwrap_or_else(|err| {
+            let answers_name = answers_path.file_name().unwrap_or_default();
+            let err = format!("invalid {}: {err}", answers_name.to_string_lossy());
+            table.push(error_row(&name, &err));
+            None
+        });
+        let results = match fs::read_to_string(&path) {
+            Ok(input) => runner::run_day(&args.selection, day, &DayInput::Input(input)),
+            Err(err) => {
+                table.push(error_row(&name, &format!("could not read it: {err}")));
+                continue;
+            }
+        };
+        table.extend(rows(&name, &results, answers.as_ref()));
+    }
+
+    write_table(&mut io::stdout().lock(), HEADER, &table).expect("Failed to write results");
+    table
+        .iter()
+        .all(|[.., status]| status == "ok" || status == "unknown")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{registry::Timed, runner::Part};
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_input_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for name in ["bob.txt", "alice.txt", "alice.answers.toml", ".hidden"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+        let names = input_files(&dir)
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names, ["alice.txt", "bob.txt"]);
+        assert_eq!(
+            answers_path(Path::new("team/alice.txt")),
+            Path::new("team/alice.answers.toml")
+        );
+    }
+
+    #[test]
+    fn test_load_answers() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        fs::write(&path, "part1 = 288\npart2 = [").unwrap();
+        let malformed = load_answers(&path).unwrap_err().to_string();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(malformed, "line 2: unclosed array, expected `]`");
+        assert!(load_answers(&path).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rows() {
+        let answers: DayAnswers = toml::from_str("part1 = 288\npart2 = 1").unwrap();
+        let result = |part, answer: u32| PartResult {
+            year: 2023,
+            day: 6,
+            part,
+            outcome: Ok(Timed {
+                answer: answer.into(),
+                parse_time: Duration::from_micros(1),
+                solve_time: Duration::from_micros(2),
+            }),
+        };
+        let results = [result(Part::One, 288), result(Part::Two, 71503)];
+        let statuses = |answers| {
+            rows("alice.txt", &results, answers)
+                .into_iter()
+                .map(|[.., status]| status)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(statuses(Some(&answers)), ["ok", "MISMATCH"]);
+        assert_eq!(statuses(None), ["unknown", "unknown"]);
+    }
+}
@@ -0,0 +1,374 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answer::Answer,
+    output::{format_duration, write_table},
+    parse::ParseError,
+    registry,
+    runner::{Part, Selection},
+    solutions::Solution,
+};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Maximum number of times each phase is run
+    #[arg(long, default_value_t = 100)]
+    iterations: usize,
+
+    /// Stop repeating a phase once it has taken this many seconds in total
+    #[arg(long, default_value = "5", value_parser = parse_seconds)]
+    max_seconds: Duration,
+
+    /// File with the results of an earlier run to compare against
+    #[arg(long, default_value = "bench_baseline.json")]
+    baseline: PathBuf,
+
+    /// Store the results of this run as the new baseline
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// Flag phases whose median got slower than the baseline by more than this percentage
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.write_str("parse"),
+            Phase::Part1 => f.write_str("part 1"),
+            Phase::Part2 => f.write_str("part 2"),
+        }
+    }
+}
+
+/// How often a single phase gets repeated
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    pub iterations: usize,
+    pub max_time: Duration,
+}
+
+impl Budget {
+    /// Runs `f` until either budget is exhausted, but at least once
+    fn sample<T>(&self, mut f: impl FnMut() -> T) -> Vec<Duration> {
+        let mut samples = vec![];
+        let started = Instant::now();
+        while samples.is_empty()
+            || (samples.len() < self.iterations && started.elapsed() < self.max_time)
+        {
+            let start = Instant::now();
+            let result = f();
+            samples.push(start.elapsed());
+            // Keeps the result from being optimized away, without timing its drop
+            std::hint::black_box(result);
+        }
+        samples
+    }
+}
+
+/// Measures parsing `input`
+pub fn measure_parse<S: Solution>(input: &str, budget: Budget) -> Result<Stats, ParseError> {
+    S::parse(input).map_err(|err| err.locate(input))?;
+    Ok(Stats::new(budget.sample(|| S::parse(input))))
+}
+
+/// Measures `solve`, on input that is only parsed once
+pub fn measure<S: Solution, T: Into<Answer>>(
+    input: &str,
+    budget: Budget,
+    solve: impl Fn(&S::Parsed) -> T,
+) -> Result<Stats, ParseError> {
+    let parsed = S::parse(input).map_err(|err| err.locate(input))?;
+    Ok(Stats::new(budget.sample(|| solve(&parsed))))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let percentile = |p: usize| samples[((samples.len() * p).div_ceil(100)).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    year: u16,
+    day: u8,
+    phase: Phase,
+    min_ns: u64,
+    median_ns: u64,
+    p95_ns: u64,
+    mean_ns: u64,
+}
+
+impl BaselineEntry {
+    fn new(year: u16, day: u8, phase: Phase, stats: &Stats) -> Self {
+        BaselineEntry {
+            year,
+            day,
+            phase,
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+            mean_ns: stats.mean.as_nanos() as u64,
+        }
+    }
+}
+
+fn load_baseline(path: &Path) -> io::Result<Vec<BaselineEntry>> {
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Replaces the entries in `baseline` that were measured again, keeping the
+/// ones of days and parts that weren't run this time
+fn merge_baseline(
+    mut baseline: HashMap<(u16, u8, Phase), BaselineEntry>,
+    entries: Vec<BaselineEntry>,
+) -> Vec<BaselineEntry> {
+    baseline.extend(
+        entries
+            .into_iter()
+            .map(|entry| ((entry.year, entry.day, entry.phase), entry)),
+    );
+    let mut merged = baseline.into_values().collect::<Vec<_>>();
+    merged.sort_by_key(|entry| (entry.year, entry.day, entry.phase));
+    merged
+}
+
+fn save_baseline(path: &Path, entries: &[BaselineEntry]) -> io::Result<()> {
+    let content = serde_json::to_string_pretty(entries)?;
+    fs::write(path, content + "\n")
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let secs = s.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(secs).map_err(|err| err.to_string())
+}
+
+/// Percentage by which `current` is slower than `baseline`
+fn change_percent(baseline: u64, current: Duration) -> f64 {
+    (current.as_nanos() as f64 - baseline as f64) / baseline.max(1) as f64 * 100.0
+}
+
+pub fn bench(args: BenchArgs) -> bool {
+    let budget = Budget {
+        iterations: args.iterations.max(1),
+        max_time: args.max_seconds,
+    };
+    let baseline = match load_baseline(&args.baseline) {
+        Ok(entries) => entries
+            .into_iter()
+            .map(|entry| ((entry.year, entry.day, entry.phase), entry))
+            .collect(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => HashMap::new(),
+        Err(err) => {
+            eprintln!("Ignoring baseline {}: {err}", args.baseline.display());
+            HashMap::new()
+        }
+    };
+
+    let year = args.selection.year();
+    let parts = args.selection.parts();
+    let mut entries = vec![];
+    let mut rows = vec![];
+    let mut success = true;
+    let mut regressions = 0;
+    for day in args.selection.days() {
+        let Ok(input) = args.selection.read_input(day) else {
+            success = false;
+            continue;
+        };
+        let mut measurements = vec![];
+        for &part in &parts {
+            let Some(implementation) =
+                registry::find(year, day, part, args.selection.implementation())
+            else {
+                continue;
+            };
+            let Some(input) = input.for_part(part) else {
+                continue;
+            };
+            // Parsing is the same for every part, so it is only measured once
+            let parse = match measurements.is_empty() {
+                true => (implementation.bench_parse)(input, budget).map(Some),
+                false => Ok(None),
+            };
+            let (parse, solve) =
+                match parse.and_then(|parse| Ok((parse, (implementation.bench)(input, budget)?))) {
+                    Ok(stats) => stats,
+                    Err(err) => {
+                        let err = err.for_day(day);
+                        eprint!("Invalid input for {year} {err}\n{}", err.diagnostic());
+                        success = false;
+                        break;
+                    }
+                };
+            measurements.extend(parse.map(|parse| (Phase::Parse, parse)));
+            measurements.push((part.into(), solve));
+        }
+
+        for (phase, stats) in measurements {
+            let change = baseline
+                .get(&(year, day, phase))
+                .map(|entry| change_percent(entry.median_ns, stats.median));
+            let change_str = match change {
+                Some(change) if change > args.threshold => {
+                    regressions += 1;
+                    format!("{change:+.1}% REGRESSION")
+                }
+                Some(change) => format!("{change:+.1}%"),
+                None => "-".to_string(),
+            };
+            rows.push([
+                year.to_string(),
+                day.to_string(),
+                phase.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.p95),
+                format_duration(stats.mean),
+                change_str,
+            ]);
+            entries.push(BaselineEntry::new(year, day, phase, &stats));
+        }
+    }
+
+    write_table(
+        &mut io::stdout().lock(),
+        [
+            "Year",
+            "Day",
+            "Phase",
+            "Min",
+            "Median",
+            "p95",
+            "Mean",
+            "vs. baseline",
+        ],
+        &rows,
+    )
+    .expect("Failed to write results");
+
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} phase(s) regressed by more than {}%",
+            args.threshold
+        );
+        success = false;
+    }
+
+    if args.save_baseline {
+        if let Err(err) = save_baseline(&args.baseline, &merge_baseline(baseline, entries)) {
+            eprintln!("Could not save baseline {}: {err}", args.baseline.display());
+            success = false;
+        }
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(50),
+                p95: Duration::from_millis(95),
+                mean: Duration::from_micros(50_500),
+            }
+        );
+
+        let single = Stats::new(vec![Duration::from_millis(7)]);
+        assert_eq!(single.min, single.p95);
+        assert_eq!(single.median, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn test_change_percent() {
+        assert_eq!(change_percent(100, Duration::from_nanos(110)), 10.0);
+        assert_eq!(change_percent(100, Duration::from_nanos(50)), -50.0);
+    }
+
+    #[test]
+    fn test_merge_baseline() {
+        let stats = |millis| Stats::new(vec![Duration::from_millis(millis)]);
+        let entry = |day, phase, millis| BaselineEntry::new(2023, day, phase, &stats(millis));
+        let baseline = [entry(6, Phase::Parse, 1), entry(9, Phase::Part1, 2)]
+            .into_iter()
+            .map(|entry| ((entry.year, entry.day, entry.phase), entry))
+            .collect();
+        let merged = merge_baseline(
+            baseline,
+            vec![entry(9, Phase::Part2, 3), entry(9, Phase::Part1, 4)],
+        );
+        assert_eq!(
+            merged
+                .iter()
+                .map(|entry| (entry.day, entry.phase, entry.median_ns))
+                .collect::<Vec<_>>(),
+            [
+                (6, Phase::Parse, 1_000_000),
+                (9, Phase::Part1, 4_000_000),
+                (9, Phase::Part2, 3_000_000)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("NaN").is_err());
+    }
+}
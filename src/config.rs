@@ -0,0 +1,207 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::{input, output::Format, solutions};
+
+/// Name of the configuration file, looked up in the current directory first
+/// and in the user's configuration directory second
+pub const FILE_NAME: &str = "aoc.toml";
+
+/// Directory for user-level settings, `~/.config/aoc` by default
+pub fn user_dir() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("aoc"))
+}
+
+/// Settings from `aoc.toml`. Command line flags take precedence over them.
+///
+/// ```toml
+/// input-dir = "inputs"
+/// year = 2023
+/// answers = "answers.toml"
+/// endpoint = "https://adventofcode.com"
+/// format = "text"
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub answers: Option<PathBuf>,
+    pub endpoint: Option<String>,
+    pub format: Option<Format>,
+    /// Session cookie used to download inputs and submit answers
+    pub session: Option<String>,
+}
+
+impl Config {
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Reads the config at `path`, which is allowed to be missing
+    pub fn read(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {err}", path.display()),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Loads the project config, filling in settings it doesn't have from
+    /// the user config
+    pub fn load() -> io::Result<Self> {
+        let project = Self::read(Path::new(FILE_NAME))?;
+        let user = match user_dir() {
+            Some(dir) => Self::read(&dir.join(FILE_NAME))?,
+            None => Self::default(),
+        };
+        Ok(project.or(user))
+    }
+
+    /// Uses the settings of `fallback` that aren't set in `self`
+    pub fn or(self, fallback: Config) -> Config {
+        Config {
+            input_dir: self.input_dir.or(fallback.input_dir),
+            year: self.year.or(fallback.year),
+            answers: self.answers.or(fallback.answers),
+            endpoint: self.endpoint.or(fallback.endpoint),
+            format: self.format.or(fallback.format),
+            session: self.session.or(fallback.session),
+        }
+    }
+}
+
+/// Command line arguments with settings that can also come from the config
+pub trait ApplyConfig {
+    /// Uses the settings from `config` for everything not given as a flag
+    fn apply(&mut self, config: &Config);
+}
+
+/// `--year`, shared by the commands that work on a single year
+#[derive(Args, Clone, Default)]
+pub struct YearArg {
+    /// Year of the event, defaults to the latest one with solutions
+    #[arg(long)]
+    pub(crate) year: Option<u16>,
+}
+
+impl YearArg {
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or(solutions::LATEST_YEAR)
+    }
+}
+
+impl ApplyConfig for YearArg {
+    fn apply(&mut self, config: &Config) {
+        self.year = self.year.or(config.year);
+    }
+}
+
+/// `--input-dir`, shared by the commands that read or create inputs
+#[derive(Args, Clone, Default)]
+pub struct InputDirArg {
+    /// Directory containing the `YYYY/day_XX.txt` inputs. Missing inputs are
+    /// downloaded to it if a session token is available. [default: inputs]
+    #[arg(long)]
+    input_dir: Option<PathBuf>,
+}
+
+impl InputDirArg {
+    /// Directory given as a flag or read from the config, if any
+    pub fn given(&self) -> Option<&Path> {
+        self.input_dir.as_deref()
+    }
+
+    pub fn dir(&self) -> &Path {
+        self.given().unwrap_or(input::DEFAULT_DIR.as_ref())
+    }
+}
+
+impl ApplyConfig for InputDirArg {
+    fn apply(&mut self, config: &Config) {
+        self.input_dir = self.input_dir.take().or_else(|| config.input_dir.clone());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(indoc! {r#"
+            input-dir = "puzzles"
+            year = 2022
+            format = "csv"
+        "#})
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                input_dir: Some(PathBuf::from("puzzles")),
+                year: Some(2022),
+                format: Some(Format::Csv),
+                ..Config::default()
+            }
+        );
+        assert!(Config::parse("inputs = \"typo\"").is_err());
+    }
+
+    #[test]
+    fn test_fallback() {
+        let project = Config {
+            year: Some(2022),
+            ..Config::default()
+        };
+        let user = Config {
+            year: Some(2023),
+            session: Some("53cr3t".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(
+            project.or(user),
+            Config {
+                year: Some(2022),
+                session: Some("53cr3t".to_string()),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_shared_args() {
+        let config = Config {
+            input_dir: Some(PathBuf::from("puzzles")),
+            year: Some(2022),
+            ..Config::default()
+        };
+        let mut year = YearArg::default();
+        assert_eq!(year.year(), solutions::LATEST_YEAR);
+        year.apply(&config);
+        assert_eq!(year.year(), 2022);
+        let mut flag = YearArg { year: Some(2021) };
+        flag.apply(&config);
+        assert_eq!(flag.year(), 2021);
+
+        let mut input_dir = InputDirArg::default();
+        assert_eq!(input_dir.dir(), Path::new(input::DEFAULT_DIR));
+        input_dir.apply(&config);
+        assert_eq!(input_dir.given(), Some(Path::new("puzzles")));
+    }
+}
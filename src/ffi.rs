@@ -0,0 +1,342 @@
+//! C interface to the 2023 solutions, declared in `ffi/aoc.h`, which is
+//! generated from the signatures in this module by running the tests with
+//! `AOC_UPDATE_HEADER=1`. The year is fixed so that adding a later year
+//! doesn't change what existing C callers get.
+//!
+//! All functions write text to a caller provided buffer: `*out_len` is the
+//! size of `out_buf` when called, and the length of the text without its
+//! terminating NUL on return. If the buffer is too small nothing is written,
+//! so the call can be repeated with a buffer of `*out_len + 1` bytes.
+use std::{ffi::c_int, panic, slice};
+
+use crate::{
+    registry,
+    runner::{self, Part},
+};
+
+/// The year all days passed through the C interface belong to
+const YEAR: u16 = 2023;
+
+pub const AOC_OK: c_int = 0;
+pub const AOC_ERR_NULL_POINTER: c_int = 1;
+pub const AOC_ERR_NO_SOLUTION: c_int = 2;
+pub const AOC_ERR_INVALID_UTF8: c_int = 3;
+pub const AOC_ERR_INVALID_INPUT: c_int = 4;
+pub const AOC_ERR_PANICKED: c_int = 5;
+pub const AOC_ERR_BUFFER_TOO_SMALL: c_int = 6;
+pub const AOC_ERR_NO_SAMPLE: c_int = 7;
+
+const CODES: [(&str, c_int, &str); 8] = [
+    ("AOC_OK", AOC_OK, "The answer was written to `out_buf`"),
+    (
+        "AOC_ERR_NULL_POINTER",
+        AOC_ERR_NULL_POINTER,
+        "A required pointer is NULL",
+    ),
+    (
+        "AOC_ERR_NO_SOLUTION",
+        AOC_ERR_NO_SOLUTION,
+        "There is no solution for this day and part",
+    ),
+    (
+        "AOC_ERR_INVALID_UTF8",
+        AOC_ERR_INVALID_UTF8,
+        "The input isn't valid UTF-8",
+    ),
+    (
+        "AOC_ERR_INVALID_INPUT",
+        AOC_ERR_INVALID_INPUT,
+        "The input is invalid, `out_buf` describes why",
+    ),
+    (
+        "AOC_ERR_PANICKED",
+        AOC_ERR_PANICKED,
+        "The solution panicked, `out_buf` has the message",
+    ),
+    (
+        "AOC_ERR_BUFFER_TOO_SMALL",
+        AOC_ERR_BUFFER_TOO_SMALL,
+        "`out_buf` needs `*out_len + 1` bytes",
+    ),
+    (
+        "AOC_ERR_NO_SAMPLE",
+        AOC_ERR_NO_SAMPLE,
+        "There is no sample for this day and part",
+    ),
+];
+
+fn part(part: u8) -> Option<Part> {
+    match part {
+        1 => Some(Part::One),
+        2 => Some(Part::Two),
+        _ => None,
+    }
+}
+
+/// Writes `text` and a terminating NUL to `out_buf` if it fits
+unsafe fn write_out(text: &str, code: c_int, out_buf: *mut u8, out_len: *mut usize) -> c_int {
+    let capacity = *out_len;
+    *out_len = text.len();
+    if text.len() >= capacity {
+        return AOC_ERR_BUFFER_TOO_SMALL;
+    }
+    let out = slice::from_raw_parts_mut(out_buf, capacity);
+    out[..text.len()].copy_from_slice(text.as_bytes());
+    out[text.len()] = 0;
+    code
+}
+
+/// Solves `part` of `day` for the input in `input_ptr` and writes the answer
+/// to `out_buf`.
+///
+/// # Safety
+///
+/// `input_ptr` has to point to `input_len` readable bytes, `out_len` to a
+/// valid `usize` and `out_buf` to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    if input_ptr.is_null() || out_buf.is_null() || out_len.is_null() {
+        return AOC_ERR_NULL_POINTER;
+    }
+    let Some(implementation) =
+        self::part(part).and_then(|part| registry::find(YEAR, day, part, None))
+    else {
+        return AOC_ERR_NO_SOLUTION;
+    };
+    let Ok(input) = std::str::from_utf8(slice::from_raw_parts(input_ptr, input_len)) else {
+        return AOC_ERR_INVALID_UTF8;
+    };
+    // Unwinding into C is undefined behavior
+    match panic::catch_unwind(|| (implementation.run)(input)) {
+        Ok(Ok(timed)) => write_out(&timed.answer.to_string(), AOC_OK, out_buf, out_len),
+        Ok(Err(err)) => {
+            let message = err.for_day(day).to_string();
+            write_out(&message, AOC_ERR_INVALID_INPUT, out_buf, out_len)
+        }
+        Err(payload) => {
+            let message = runner::panic_message(&*payload);
+            write_out(&message, AOC_ERR_PANICKED, out_buf, out_len)
+        }
+    }
+}
+
+/// Writes the sample for `part` of `day` to `out_buf`.
+///
+/// # Safety
+///
+/// `out_len` has to point to a valid `usize` and `out_buf` to `*out_len`
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_sample_input(
+    day: u8,
+    part: u8,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    if out_buf.is_null() || out_len.is_null() {
+        return AOC_ERR_NULL_POINTER;
+    }
+    match self::part(part).and_then(|part| registry::sample(YEAR, day, part)) {
+        Some(sample) => write_out(sample.input, AOC_OK, out_buf, out_len),
+        None => AOC_ERR_NO_SAMPLE,
+    }
+}
+
+/// Writes the answer to the sample for `part` of `day` to `out_buf`.
+///
+/// # Safety
+///
+/// `out_len` has to point to a valid `usize` and `out_buf` to `*out_len`
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_sample_answer(
+    day: u8,
+    part: u8,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    if out_buf.is_null() || out_len.is_null() {
+        return AOC_ERR_NULL_POINTER;
+    }
+    let expected = self::part(part).and_then(|part| {
+        registry::sample(YEAR, day, part).and_then(|sample| sample.expected(part))
+    });
+    match expected {
+        Some(answer) => write_out(&answer.to_string(), AOC_OK, out_buf, out_len),
+        None => AOC_ERR_NO_SAMPLE,
+    }
+}
+
+/// C spelling of the Rust types used in the exported signatures
+fn c_type(rust: &str) -> &'static str {
+    match rust {
+        "u8" => "uint8_t",
+        "usize" => "size_t",
+        "*const u8" => "const uint8_t *",
+        "*mut u8" => "uint8_t *",
+        "*mut usize" => "size_t *",
+        _ => panic!("no C type for `{rust}`, add it to ffi::c_type"),
+    }
+}
+
+/// C prototypes of the `extern "C"` functions in this file, each preceded by
+/// the summary of its doc comment
+fn prototypes() -> String {
+    let mut prototypes = Vec::new();
+    let mut docs = Vec::new();
+    let mut lines = include_str!("ffi.rs").lines();
+    while let Some(line) = lines.next() {
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(doc.trim());
+            continue;
+        }
+        let Some(name) = line
+            .strip_prefix("pub unsafe extern \"C\" fn ")
+            .and_then(|rest| rest.strip_suffix('('))
+        else {
+            if !line.starts_with("#[") {
+                docs.clear();
+            }
+            continue;
+        };
+        let params = lines
+            .by_ref()
+            .take_while(|line| !line.starts_with(')'))
+            .map(|param| {
+                let (name, ty) = param.trim().trim_end_matches(',').split_once(": ").unwrap();
+                match c_type(ty) {
+                    pointer if pointer.ends_with('*') => format!("{pointer}{name}"),
+                    ty => format!("{ty} {name}"),
+                }
+            })
+            .collect::<Vec<_>>();
+        let summary = docs
+            .iter()
+            .take_while(|doc| !doc.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+        prototypes.push(format!(
+            "/* {summary} */\nint {name}({});\n",
+            params.join(", ")
+        ));
+        docs.clear();
+    }
+    prototypes.join("\n")
+}
+
+/// Contents of `ffi/aoc.h`
+pub fn header() -> String {
+    let codes = CODES
+        .iter()
+        .map(|(name, value, doc)| format!("/* {doc} */\n#define {name} {value}\n"))
+        .collect::<Vec<_>>()
+        .join("");
+    let prototypes = prototypes();
+    format!(
+        r#"/* Generated from src/ffi.rs, see there for details. Do not edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {{
+#endif
+
+/* Every `day` is a day of {YEAR} */
+#define AOC_YEAR {YEAR}
+
+{codes}
+/*
+ * `*out_len` is the size of `out_buf` when called, and the length of the text
+ * written to it, without the terminating NUL, on return.
+ */
+
+{prototypes}
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+"#
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{env, fs};
+
+    #[cfg(all(
+        feature = "y2023-day-06",
+        feature = "y2023-day-09",
+        feature = "y2023-day-21"
+    ))]
+    fn call(f: impl FnOnce(*mut u8, *mut usize) -> c_int, capacity: usize) -> (c_int, String) {
+        let mut buf = vec![0u8; capacity];
+        let mut len = capacity;
+        let code = f(buf.as_mut_ptr(), &mut len);
+        let text = match code {
+            AOC_ERR_BUFFER_TOO_SMALL => len.to_string(),
+            _ => String::from_utf8_lossy(&buf[..len]).to_string(),
+        };
+        (code, text)
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "y2023-day-06",
+        feature = "y2023-day-09",
+        feature = "y2023-day-21"
+    ))]
+    fn test_solve() {
+        let input = b"Time:      7  15   30\nDistance:  9  40  200\n";
+        let solve =
+            |day, buf, len| unsafe { aoc_solve(day, 1, input.as_ptr(), input.len(), buf, len) };
+
+        assert_eq!(
+            call(|buf, len| solve(6, buf, len), 16),
+            (AOC_OK, "288".to_string())
+        );
+        assert_eq!(
+            call(|buf, len| solve(6, buf, len), 3),
+            (AOC_ERR_BUFFER_TOO_SMALL, "3".to_string())
+        );
+        assert_eq!(
+            call(|buf, len| solve(30, buf, len), 16).0,
+            AOC_ERR_NO_SOLUTION
+        );
+
+        let (code, message) = call(|buf, len| solve(9, buf, len), 256);
+        assert_eq!(code, AOC_ERR_INVALID_INPUT);
+        assert!(message.starts_with("day 9, line 1"), "{message}");
+
+        let (code, _) = call(|buf, len| unsafe { aoc_sample_input(21, 1, buf, len) }, 16);
+        assert_eq!(code, AOC_ERR_NO_SAMPLE);
+        let (code, answer) = call(|buf, len| unsafe { aoc_sample_answer(6, 2, buf, len) }, 16);
+        assert_eq!((code, answer.as_str()), (AOC_OK, "71503"));
+    }
+
+    #[test]
+    fn test_header() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/ffi/aoc.h");
+        if env::var_os("AOC_UPDATE_HEADER").is_some() {
+            fs::write(path, header()).unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            header(),
+            "ffi/aoc.h is out of date, run the tests with AOC_UPDATE_HEADER=1"
+        );
+    }
+}
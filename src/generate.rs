@@ -0,0 +1,106 @@
+use std::{fs, io, path::PathBuf};
+
+use clap::{builder::RangedU64ValueParser, Args};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    config::{ApplyConfig, Config, YearArg},
+    registry, solutions,
+};
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    #[command(flatten)]
+    year: YearArg,
+
+    /// Day to generate the input for
+    #[arg(long)]
+    day: u8,
+
+    /// The same seed always produces the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How large the input should be, e.g. the number of lines or the width of
+    /// a grid, depending on the day
+    #[arg(
+        long,
+        default_value_t = 100,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    size: usize,
+
+    /// File to write the input to instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+impl ApplyConfig for GenerateArgs {
+    fn apply(&mut self, config: &Config) {
+        self.year.apply(config);
+    }
+}
+
+/// Generates the input for `day` of `year`, or `None` if the day has no generator
+pub fn generate_input(year: u16, day: u8, seed: u64, size: usize) -> Option<String> {
+    let generator = registry::generator(year, day)?;
+    Some((generator.generate)(&mut StdRng::seed_from_u64(seed), size))
+}
+
+pub fn generate(args: GenerateArgs) -> bool {
+    let (year, day) = (args.year.year(), args.day);
+    let Some(input) = generate_input(year, day, args.seed, args.size) else {
+        match solutions::disabled_days(year).contains(&day) {
+            true => eprintln!("{}", crate::Error::Unavailable { year, day }),
+            false => eprintln!("There is no generator for day {day} of {year}"),
+        }
+        return false;
+    };
+    let result = match &args.output {
+        Some(path) => fs::write(path, input),
+        None => io::Write::write_all(&mut io::stdout().lock(), input.as_bytes()),
+    };
+    result
+        .inspect_err(|err| eprintln!("Could not write the input: {err}"))
+        .is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::{self, Part};
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_generators() {
+        let disabled = crate::solutions::disabled_days(2023);
+        for day in (1..=25).filter(|day| !disabled.contains(day)) {
+            assert_eq!(
+                generate_input(2023, day, 1, 10),
+                generate_input(2023, day, 1, 10),
+                "day {day} isn't deterministic"
+            );
+            // Generators that ignore the size always make a full sized input,
+            // which is too slow to solve for more than a few seeds
+            let sized = generate_input(2023, day, 0, 1) != generate_input(2023, day, 0, 10);
+            let (seeds, sizes) = match sized {
+                true => (0..10, &[1, 2, 3, 10][..]),
+                false => (0..3, &[10][..]),
+            };
+            for (seed, &size) in seeds.cartesian_product(sizes) {
+                let input = generate_input(2023, day, seed, size).unwrap();
+                let parts = Part::ALL
+                    .into_iter()
+                    .filter_map(|part| Some((part, registry::find(2023, day, part, None)?)));
+                for (part, implementation) in parts {
+                    let result = runner::run_isolated(implementation.run, &input, None);
+                    assert!(
+                        matches!(result, Ok(Ok(_))),
+                        "day {day} part {part} with seed {seed} and size {size}:\n{input}"
+                    );
+                }
+            }
+        }
+    }
+}
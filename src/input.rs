@@ -0,0 +1,205 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::config;
+
+/// Path used to signal that the input should be read from stdin
+pub const STDIN_PATH: &str = "-";
+
+/// Where inputs are kept, unless another directory is configured
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Where inputs are downloaded from, unless another endpoint is configured
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session token used for downloads
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Default location of a day's input within `input_dir`
+pub fn default_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir
+        .join(year.to_string())
+        .join(format!("day_{day:02}.txt"))
+}
+
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN_PATH) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// File the session token is read from if it isn't set otherwise
+pub fn session_file() -> Option<PathBuf> {
+    Some(config::user_dir()?.join("session"))
+}
+
+/// The session cookie of the logged in user, which is needed to download
+/// inputs. Taken from `AOC_SESSION`, the `configured` one or `session_file`,
+/// in that order.
+pub fn session_token(configured: Option<&str>) -> Option<String> {
+    let token = match (env::var(SESSION_VAR), configured) {
+        (Ok(token), _) => token,
+        (Err(_), Some(token)) => token.to_string(),
+        (Err(_), None) => fs::read_to_string(session_file()?).ok()?,
+    };
+    Some(token.trim().to_string()).filter(|token| !token.is_empty())
+}
+
+/// Downloads inputs from an Advent of Code compatible server
+pub struct Downloader {
+    pub endpoint: String,
+    pub session: String,
+}
+
+impl Downloader {
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{year}/day/{day}/input",
+            self.endpoint.trim_end_matches('/')
+        )
+    }
+
+    pub fn download(&self, year: u16, day: u8) -> io::Result<String> {
+        let url = self.url(year, day);
+        let failed =
+            |err: ureq::Error| io::Error::other(format!("could not download {url}: {err}"));
+        ureq::get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", env!("CARGO_PKG_NAME"))
+            .call()
+            .map_err(failed)?
+            .body_mut()
+            .read_to_string()
+            .map_err(failed)
+    }
+}
+
+/// Reads the input at `path`, downloading and saving it there first if it
+/// doesn't exist yet. An empty file, as created by `new`, counts as missing.
+pub fn cached_input(
+    path: &Path,
+    year: u16,
+    day: u8,
+    downloader: Option<&Downloader>,
+) -> io::Result<String> {
+    let cached = fs::read_to_string(path).and_then(|input| match input.is_empty() {
+        true => Err(io::ErrorKind::NotFound.into()),
+        false => Ok(input),
+    });
+    match (cached, downloader) {
+        (Err(err), Some(downloader)) if err.kind() == io::ErrorKind::NotFound => {
+            let input = downloader.download(year, day)?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, &input)?;
+            Ok(input)
+        }
+        (cached, _) => cached,
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        process,
+        thread::{self, JoinHandle},
+    };
+
+    /// Answers a single request with `status` and `body`, then shuts down.
+    /// Returns the URL of the server and a handle yielding the whole request.
+    pub fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                request += &line;
+                match line.to_lowercase().split_once(':') {
+                    Some(("content-length", length)) => {
+                        content_length = length.trim().parse().unwrap()
+                    }
+                    None if line == "\r\n" => break,
+                    _ => (),
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request += &String::from_utf8(content).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_download_once() {
+        let (endpoint, server) = serve_once("200 OK", "1 2 3\n");
+        let downloader = Downloader {
+            endpoint,
+            session: "53cr3t".to_string(),
+        };
+        let dir = temp_dir("download");
+        let path = default_path(&dir, 2023, 5);
+
+        assert_eq!(
+            cached_input(&path, 2023, 5, Some(&downloader)).unwrap(),
+            "1 2 3\n"
+        );
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2023/day/5/input http/1.1\r\n"));
+        assert!(request.contains("cookie: session=53cr3t\r\n"));
+
+        // The server is gone, so this has to come from the cache
+        assert_eq!(
+            cached_input(&path, 2023, 5, Some(&downloader)).unwrap(),
+            "1 2 3\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_failure() {
+        let (endpoint, server) = serve_once("404 Not Found", "");
+        let downloader = Downloader {
+            endpoint: endpoint + "/",
+            session: "53cr3t".to_string(),
+        };
+        let dir = temp_dir("download-failure");
+        let path = default_path(&dir, 2023, 26);
+
+        assert!(cached_input(&path, 2023, 26, Some(&downloader)).is_err());
+        server.join().unwrap();
+        assert!(!path.exists());
+
+        let err = cached_input(&path, 2023, 26, None).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
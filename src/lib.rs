@@ -0,0 +1,101 @@
+//! Solutions to Advent of Code, along with the tooling to run, benchmark,
+//! verify and submit them. Use [`solve`] to get the answer for an input:
+//!
+//! ```
+//! use advent_of_code_2023::{solve, Part};
+//!
+//! # #[cfg(feature = "y2023-day-06")]
+//! # {
+//! let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+//! assert_eq!(solve(2023, 6, Part::One, input).unwrap().to_string(), "288");
+//! # }
+//! ```
+pub mod answer;
+pub mod batch;
+pub mod bench;
+pub mod config;
+pub mod ffi;
+pub mod generate;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod solutions;
+pub mod submit;
+pub mod utils;
+pub mod verify;
+pub mod watch;
+
+use std::fmt::{self, Display};
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use runner::Part;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// There is no solution for this part
+    NoSolution { year: u16, day: u8, part: Part },
+    /// The solution for this day was left out of the build
+    Unavailable { year: u16, day: u8 },
+    /// The input isn't valid for this day
+    InvalidInput(ParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSolution { year, day, part } => {
+                write!(f, "there is no solution for {year} day {day} part {part}")
+            }
+            Error::Unavailable { year, day } => write!(
+                f,
+                "{year} day {day} is unavailable in this build, enable the `{}` feature",
+                solutions::feature(*year, *day)
+            ),
+            Error::InvalidInput(err) => write!(f, "invalid input for {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Solves `part` of the puzzle of `day` in `year` for `input`, using the
+/// primary implementation
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Result<Answer, Error> {
+    if solutions::disabled_days(year).contains(&day) {
+        return Err(Error::Unavailable { year, day });
+    }
+    let implementation =
+        registry::find(year, day, part, None).ok_or(Error::NoSolution { year, day, part })?;
+    (implementation.run)(input)
+        .map(|timed| timed.answer)
+        .map_err(|err| Error::InvalidInput(err.for_day(day)))
+}
+
+#[cfg(all(test, feature = "y2023-day-09"))]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_solve() {
+        let sample = registry::sample(2023, 9, Part::One).unwrap();
+        assert_eq!(
+            solve(2023, 9, Part::One, sample.input).unwrap(),
+            Answer::from(114)
+        );
+        assert!(matches!(
+            solve(1999, 9, Part::One, sample.input),
+            Err(Error::NoSolution { year: 1999, .. })
+        ));
+        let Err(Error::InvalidInput(err)) = solve(2023, 9, Part::One, "1 2 x") else {
+            panic!("Expected invalid input");
+        };
+        assert_eq!(err.day, Some(9));
+    }
+}
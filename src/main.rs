@@ -1,17 +1,170 @@
-pub mod solutions;
-pub mod utils;
+use std::{io, process};
 
-use aoc::aoc;
-use clap::{arg, Parser};
-use solutions::*;
+use advent_of_code_2023::{
+    batch, bench,
+    config::{ApplyConfig, Config},
+    generate,
+    output::{self, Format},
+    registry,
+    runner::{self, Selection},
+    scaffold, solutions, submit, verify, watch,
+};
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand};
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-    #[arg(long)]
-    day: u8,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Print the days that have a solution, per year, and exit
+    #[arg(long, group = "selection")]
+    list: bool,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time parsing and solving of the selected days
+    Bench(bench::BenchArgs),
+    /// Check the selected days against the known good answers
+    Verify(verify::VerifyArgs),
+    /// Create the module, input file and sample stub for a new day
+    New(scaffold::NewArgs),
+    /// Solve a single part and submit the answer
+    Submit(submit::SubmitArgs),
+    /// Run the selected days again whenever their input changes
+    Watch(watch::WatchArgs),
+    /// Run a single day on every input in a directory and check their answers
+    Batch(batch::BatchArgs),
+    /// Write a random input for a day
+    Generate(generate::GenerateArgs),
+    /// Serve the solutions over HTTP
+    #[cfg(feature = "serve")]
+    Serve(advent_of_code_2023::serve::ServeArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// [default: text]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+impl Cli {
+    fn selection(&self) -> Option<&Selection> {
+        match &self.command {
+            Some(Command::Bench(args)) => Some(&args.selection),
+            Some(Command::Verify(args)) => Some(&args.selection),
+            Some(Command::New(_)) => None,
+            Some(Command::Submit(args)) => Some(&args.selection),
+            Some(Command::Watch(args)) => Some(&args.selection),
+            Some(Command::Batch(args)) => Some(&args.selection),
+            Some(Command::Generate(_)) => None,
+            #[cfg(feature = "serve")]
+            Some(Command::Serve(_)) => None,
+            None => Some(&self.run.selection),
+        }
+    }
+
+    /// Uses the settings from `config` for everything not given as a flag
+    fn apply(&mut self, config: &Config) {
+        match &mut self.command {
+            Some(Command::Bench(args)) => args.selection.apply(config),
+            Some(Command::Verify(args)) => args.apply(config),
+            Some(Command::New(args)) => args.apply(config),
+            Some(Command::Submit(args)) => args.selection.apply(config),
+            Some(Command::Watch(args)) => args.selection.apply(config),
+            Some(Command::Batch(args)) => args.apply(config),
+            Some(Command::Generate(args)) => args.apply(config),
+            #[cfg(feature = "serve")]
+            Some(Command::Serve(args)) => args.apply(config),
+            None => {
+                self.run.selection.apply(config);
+                self.run.format = self.run.format.or(config.format);
+            }
+        }
+    }
+}
+
+fn list() {
+    for &year in solutions::YEARS {
+        let days = registry::days(year);
+        if !days.is_empty() {
+            println!("{year}: {}", runner::format_days(&days));
+        }
+        let disabled = solutions::disabled_days(year);
+        if !disabled.is_empty() {
+            println!(
+                "{year} unavailable in this build: {}",
+                runner::format_days(&disabled)
+            );
+        }
+    }
+    for alternative in registry::alternatives() {
+        println!(
+            "{} day {} part {}: {}",
+            alternative.year, alternative.day, alternative.part, alternative.name
+        );
+    }
+}
+
+fn run(args: RunArgs) -> bool {
+    let (results, success) = runner::run(&args.selection);
+    if args.selection.example() {
+        return verify::compare_with_samples(&results) && success;
+    }
+    output::write_results(
+        &mut io::stdout().lock(),
+        &results,
+        args.format.unwrap_or_default(),
+    )
+    .expect("Failed to write results");
+    success
 }
 
 fn main() {
-    let args = Cli::parse();
-    aoc!(args.day => day_{:02}::print_solution(), 1..25)
+    let mut args = Cli::parse();
+    if args.list {
+        list();
+        return;
+    }
+    match Config::load() {
+        Ok(config) => args.apply(&config),
+        Err(err) => Cli::command()
+            .error(ErrorKind::Io, format!("could not load the config, {err}"))
+            .exit(),
+    }
+    if let Some(Err(err)) = args.selection().map(Selection::validate) {
+        Cli::command().error(ErrorKind::InvalidValue, err).exit();
+    }
+    let validated = match &args.command {
+        Some(Command::Submit(submit_args)) => submit_args.validate(),
+        Some(Command::Watch(watch_args)) => watch_args.validate(),
+        Some(Command::Batch(batch_args)) => batch_args.validate(),
+        _ => Ok(()),
+    };
+    if let Err(err) = validated {
+        Cli::command().error(ErrorKind::InvalidValue, err).exit();
+    }
+    let success = match args.command {
+        Some(Command::Bench(bench_args)) => bench::bench(bench_args),
+        Some(Command::Verify(verify_args)) => verify::verify(verify_args),
+        Some(Command::New(new_args)) => scaffold::new_day(new_args),
+        Some(Command::Submit(submit_args)) => submit::submit(submit_args),
+        Some(Command::Watch(watch_args)) => watch::watch(watch_args),
+        Some(Command::Batch(batch_args)) => batch::batch(batch_args),
+        Some(Command::Generate(generate_args)) => generate::generate(generate_args),
+        #[cfg(feature = "serve")]
+        Some(Command::Serve(serve_args)) => advent_of_code_2023::serve::serve(serve_args),
+        None => run(args.run),
+    };
+    if !success {
+        process::exit(1);
+    }
 }
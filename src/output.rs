@@ -0,0 +1,315 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::runner::PartResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Aligned table for humans
+    #[default]
+    #[value(alias = "table")]
+    #[serde(alias = "table")]
+    Text,
+    Json,
+    Csv,
+    Tsv,
+}
+
+/// One result per part in the machine-readable formats. Failed parts have
+/// an `error` instead of an answer and timings.
+#[derive(Serialize)]
+pub struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    answer_type: Option<&'static str>,
+    parse_ms: Option<f64>,
+    solve_ms: Option<f64>,
+    error: Option<String>,
+}
+
+const COLUMNS: [&str; 9] = [
+    "year",
+    "day",
+    "part",
+    "status",
+    "answer",
+    "answer_type",
+    "parse_ms",
+    "solve_ms",
+    "error",
+];
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1e6
+}
+
+impl From<&PartResult> for Record {
+    fn from(result: &PartResult) -> Self {
+        let (year, day, part) = (result.year, result.day, result.part.number());
+        match &result.outcome {
+            Ok(timed) => Record {
+                year,
+                day,
+                part,
+                status: "ok",
+                answer: Some(timed.answer.to_string()),
+                answer_type: Some(timed.answer.kind()),
+                parse_ms: Some(millis(timed.parse_time)),
+                solve_ms: Some(millis(timed.solve_time)),
+                error: None,
+            },
+            Err(err) => Record {
+                year,
+                day,
+                part,
+                status: "error",
+                answer: None,
+                answer_type: None,
+                parse_ms: None,
+                solve_ms: None,
+                error: Some(err.clone()),
+            },
+        }
+    }
+}
+
+impl Record {
+    fn fields(&self) -> [String; 9] {
+        let text = |value: Option<&str>| value.unwrap_or_default().to_string();
+        let number = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.status.to_string(),
+            text(self.answer.as_deref()),
+            text(self.answer_type),
+            number(self.parse_ms),
+            number(self.solve_ms),
+            text(self.error.as_deref()),
+        ]
+    }
+}
+
+pub fn write_results(
+    out: &mut impl Write,
+    results: &[PartResult],
+    format: Format,
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            let rows = results
+                .iter()
+                .map(|result| match &result.outcome {
+                    Ok(timed) => [
+                        result.year.to_string(),
+                        result.day.to_string(),
+                        result.part.to_string(),
+                        timed.answer.to_string(),
+                        format_duration(timed.parse_time + timed.solve_time),
+                        "ok".to_string(),
+                    ],
+                    Err(err) => [
+                        result.year.to_string(),
+                        result.day.to_string(),
+                        result.part.to_string(),
+                        "-".to_string(),
+                        "-".to_string(),
+                        format!("ERROR: {err}"),
+                    ],
+                })
+                .collect::<Vec<_>>();
+            write_table(
+                out,
+                ["Year", "Day", "Part", "Answer", "Time", "Status"],
+                &rows,
+            )
+        }
+        Format::Json => {
+            let records = results.iter().map(Record::from).collect::<Vec<_>>();
+            serde_json::to_writer_pretty(&mut *out, &records)?;
+            writeln!(out)
+        }
+        Format::Csv => write_delimited(out, results, ',', csv_escape),
+        Format::Tsv => write_delimited(out, results, '\t', tsv_escape),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    match duration.as_secs_f64() {
+        secs if secs >= 1.0 => format!("{secs:.2}s"),
+        secs if secs >= 0.001 => format!("{:.2}ms", secs * 1e3),
+        secs => format!("{:.2}µs", secs * 1e6),
+    }
+}
+
+pub fn write_table<const N: usize>(
+    out: &mut impl Write,
+    header: [&str; N],
+    rows: &[[String; N]],
+) -> io::Result<()> {
+    let header = header.map(String::from);
+
+    let widths = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let write_row = |out: &mut dyn Write, row: &[String]| {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", cells.join(" | ").trim_end())
+    };
+
+    write_row(out, &header)?;
+    let separator = widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>();
+    writeln!(out, "{}", separator.join("-+-"))?;
+    for row in rows {
+        write_row(out, row)?;
+    }
+    Ok(())
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// TSV has no quoting, so the characters that would break a row are escaped
+fn tsv_escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn write_delimited(
+    out: &mut impl Write,
+    results: &[PartResult],
+    delimiter: char,
+    escape: fn(&str) -> String,
+) -> io::Result<()> {
+    let delimiter = delimiter.to_string();
+    writeln!(out, "{}", COLUMNS.join(&delimiter))?;
+    for record in results.iter().map(Record::from) {
+        let fields = record.fields().map(|field| escape(&field));
+        writeln!(out, "{}", fields.join(&delimiter))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{answer::Answer, registry::Timed, runner::Part};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                year: 2023,
+                day: 1,
+                part: Part::One,
+                outcome: Ok(Timed {
+                    answer: Answer::from(142),
+                    parse_time: Duration::from_micros(500),
+                    solve_time: Duration::from_micros(1000),
+                }),
+            },
+            PartResult {
+                year: 2023,
+                day: 25,
+                part: Part::Two,
+                outcome: Ok(Timed {
+                    answer: Answer::from("Merry, \"Christmas\""),
+                    parse_time: Duration::from_micros(5),
+                    solve_time: Duration::from_micros(15),
+                }),
+            },
+            PartResult {
+                year: 2023,
+                day: 25,
+                part: Part::One,
+                outcome: Err("panicked: oh\tno".to_string()),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = vec![];
+        write_results(&mut out, &results(), format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            render(Format::Text),
+            indoc! {"
+                Year | Day | Part | Answer             | Time    | Status
+                -----+-----+------+--------------------+---------+-----------------------
+                2023 | 1   | 1    | 142                | 1.50ms  | ok
+                2023 | 25  | 2    | Merry, \"Christmas\" | 20.00µs | ok
+                2023 | 25  | 1    | -                  | -       | ERROR: panicked: oh\tno
+            "}
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            render(Format::Csv),
+            indoc! {r#"
+                year,day,part,status,answer,answer_type,parse_ms,solve_ms,error
+                2023,1,1,ok,142,unsigned,0.5,1,
+                2023,25,2,ok,"Merry, ""Christmas""",text,0.005,0.015,
+                2023,25,1,error,,,,,panicked: oh	no
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_tsv() {
+        assert_eq!(
+            render(Format::Tsv),
+            indoc! {r#"
+                year	day	part	status	answer	answer_type	parse_ms	solve_ms	error
+                2023	1	1	ok	142	unsigned	0.5	1	
+                2023	25	2	ok	Merry, "Christmas"	text	0.005	0.015	
+                2023	25	1	error					panicked: oh\tno
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let records: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+        assert_eq!(records[0]["answer_type"], "unsigned");
+        assert_eq!(records[0]["solve_ms"], 1.0);
+        assert_eq!(records[2]["status"], "error");
+        assert_eq!(records[2]["answer"], serde_json::Value::Null);
+        assert_eq!(records[2]["error"], "panicked: oh\tno");
+    }
+}
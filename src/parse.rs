@@ -0,0 +1,303 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Where in the input a `ParseError` occurred
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Starting at 1
+    pub line: usize,
+    /// Within `line`, in characters and starting at 1
+    pub column: usize,
+    /// The full text of `line`
+    pub source_line: String,
+}
+
+/// Error for malformed puzzle input, shared by all days
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// Set by `locate`
+    pub location: Option<Location>,
+    /// The offending text
+    pub text: String,
+    /// Description of what should have been there instead
+    pub expected: String,
+    /// Example of what the input should look like
+    pub hint: Option<&'static str>,
+    /// Address of `text` in the input. As parsers work on slices of the input,
+    /// this allows `locate` to find the line and column of the error without
+    /// every parser having to keep track of offsets.
+    address: usize,
+}
+
+impl ParseError {
+    /// Creates an error for `found`, which should be a slice of the input
+    pub fn new(found: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            location: None,
+            text: found.to_string(),
+            expected: expected.into(),
+            hint: None,
+            address: found.as_ptr() as usize,
+        }
+    }
+
+    pub fn for_day(self, day: u8) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Adds a hint, unless a more specific one was already given closer to
+    /// where the error happened
+    pub fn with_hint(self, hint: &'static str) -> Self {
+        ParseError {
+            hint: self.hint.or(Some(hint)),
+            ..self
+        }
+    }
+
+    /// Fills in the line and column if the offending text is part of `input`
+    pub fn locate(self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.location.is_some() || !(start..=start + input.len()).contains(&self.address) {
+            return self;
+        }
+        let before = &input[..self.address - start];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let source_line = input[line_start..].lines().next().unwrap_or_default();
+        ParseError {
+            location: Some(Location {
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+                source_line: source_line.to_string(),
+            }),
+            ..self
+        }
+    }
+
+    /// Renders the offending line with a caret under the error, followed by
+    /// the hint. Empty if the error couldn't be located in the input.
+    ///
+    /// ```text
+    ///   |
+    /// 2 | px{a?2006:A,R}
+    ///   |     ^^^^^ expected '>' or '<'
+    ///   = hint: rules look like `a<2006:qkq`
+    /// ```
+    pub fn diagnostic(&self) -> String {
+        let Some(Location {
+            line,
+            column,
+            source_line,
+        }) = &self.location
+        else {
+            return String::new();
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        let remaining = source_line.chars().count() + 1 - column;
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |text| text.chars().count());
+        let carets = "^".repeat(width.clamp(1, remaining.max(1)));
+        let mut diagnostic = format!(
+            "{gutter} |\n{line} | {source_line}\n{gutter} | {}{carets} expected {}\n",
+            " ".repeat(*column - 1),
+            self.expected
+        );
+        if let Some(hint) = &self.hint {
+            diagnostic += &format!("{gutter} = hint: {hint}\n");
+        }
+        diagnostic
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {day}")),
+            self.location.as_ref().map(|l| format!("line {}", l.line)),
+            self.location
+                .as_ref()
+                .map(|l| format!("column {}", l.column)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "expected {}", self.expected)?;
+        match self.text.as_str() {
+            "" => write!(f, ", found nothing"),
+            text => write!(f, ", found '{text}'"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Like `str::split_once`, but fails if `delimiter` is missing
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(s, format!("'{delimiter}'")))
+}
+
+/// Like `str::strip_prefix`, but fails if `prefix` is missing
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("'{prefix}'")))
+}
+
+/// Like `str::strip_suffix`, but fails if `suffix` is missing
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix)
+        .ok_or_else(|| ParseError::new(&s[s.len()..], format!("'{suffix}'")))
+}
+
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+/// Parses all whitespace separated numbers in `s`
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_ascii_whitespace().map(number).collect()
+}
+
+/// Parses each character of each line of `input`. `parse` gets the character
+/// as a slice of the input, so that errors point at the right location.
+pub fn matrix<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(idx, c)| parse(&line[idx..idx + c.len_utf8()]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Like `matrix`, but fails unless there is at least one line and all lines
+/// are as long as the first one
+pub fn grid<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let grid = matrix(input, parse)?;
+    let width = grid.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(ParseError::new(input, "at least one line"));
+    }
+    match input.lines().find(|line| line.chars().count() != width) {
+        Some(line) => Err(ParseError::new(
+            line,
+            format!("{width} characters, like the first line"),
+        )),
+        None => Ok(grid),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    const INPUT: &str = indoc! {"
+        a -> b
+        c => d
+    "};
+
+    #[test]
+    fn test_locate() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = split_once(line, " -> ").unwrap_err().locate(INPUT);
+        assert_eq!(
+            err.location,
+            Some(Location {
+                line: 2,
+                column: 1,
+                source_line: "c => d".to_string()
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected ' -> ', found 'c => d'"
+        );
+
+        let err = number::<u8>(&line[5..])
+            .unwrap_err()
+            .for_day(3)
+            .locate(INPUT);
+        assert_eq!(
+            err.to_string(),
+            "day 3, line 2, column 6: expected a number, found 'd'"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |s: &str| number::<u8>(s);
+        assert_eq!(grid("12\n34\n", digit).unwrap(), [[1, 2], [3, 4]]);
+        let err = grid("12\n345\n", digit).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected 2 characters, like the first line, found '345'"
+        );
+        assert!(grid("", digit).is_err());
+        assert!(grid("1x\n", digit).is_err());
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = split_once(line, " -> ")
+            .and_then(|(_, to)| number::<u8>(to))
+            .or_else(|_| number::<u8>(&line[2..4]))
+            .unwrap_err()
+            .with_hint("arrows look like `a -> b`")
+            .with_hint("ignored, as there already is a hint")
+            .locate(INPUT);
+        assert_eq!(
+            err.diagnostic(),
+            indoc! {"
+                  |
+                2 | c => d
+                  |   ^^ expected a number
+                  = hint: arrows look like `a -> b`
+            "}
+        );
+
+        let err = strip_suffix(line, ";").unwrap_err().locate(INPUT);
+        assert_eq!(
+            err.diagnostic(),
+            indoc! {"
+                  |
+                2 | c => d
+                  |       ^ expected ';'
+            "}
+        );
+    }
+
+    #[test]
+    fn test_locate_outside_input() {
+        let copy = INPUT.to_string();
+        let err = number::<u8>(&copy).unwrap_err().locate(INPUT);
+        assert_eq!(err.location, None);
+        assert_eq!(err.diagnostic(), "");
+        assert_eq!(
+            number::<u8>("").unwrap_err().to_string(),
+            "expected a number, found nothing"
+        );
+    }
+}
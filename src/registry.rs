@@ -0,0 +1,199 @@
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+
+use crate::{
+    answer::Answer,
+    bench::{Budget, Stats},
+    parse::ParseError,
+    runner::Part,
+    solutions::Solution,
+};
+
+/// One way of solving a part of a day, registered with the `#[aoc]` attribute
+pub struct Implementation {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    /// Whether this is the day's `Solution` impl rather than an alternative
+    pub primary: bool,
+    pub run: fn(&str) -> Result<Timed, ParseError>,
+    /// Measures parsing the input for this implementation
+    pub bench_parse: fn(&str, Budget) -> Result<Stats, ParseError>,
+    /// Measures solving, without parsing
+    pub bench: fn(&str, Budget) -> Result<Stats, ParseError>,
+}
+
+inventory::collect!(Implementation);
+
+/// Example input from a puzzle description, registered with the `#[aoc]`
+/// attribute along with the answers it should produce
+pub struct Sample {
+    pub year: u16,
+    pub day: u8,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+inventory::collect!(Sample);
+
+impl Sample {
+    pub fn expected(&self, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+        .map(Answer::from)
+    }
+}
+
+/// Produces random inputs for a day, registered with the `#[aoc]` attribute.
+/// `size` scales the input, e.g. the number of lines or the width of a grid.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    pub generate: fn(&mut StdRng, usize) -> String,
+}
+
+inventory::collect!(Generator);
+
+pub fn generator(year: u16, day: u8) -> Option<&'static Generator> {
+    inventory::iter::<Generator>().find(|generator| generator.year == year && generator.day == day)
+}
+
+/// An answer along with the time it took to get there
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub fn run<S: Solution, T: Into<Answer>>(
+    input: &str,
+    solve: impl Fn(&S::Parsed) -> T,
+) -> Result<Timed, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|err| err.locate(input))?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve(&parsed).into();
+    Ok(Timed {
+        answer,
+        parse_time,
+        solve_time: start.elapsed(),
+    })
+}
+
+pub fn implementations() -> impl Iterator<Item = &'static Implementation> {
+    inventory::iter::<Implementation>()
+}
+
+/// Years that have at least one solution, in ascending order
+pub fn years() -> Vec<u16> {
+    implementations()
+        .map(|implementation| implementation.year)
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// Days of `year` that have at least one solution, in ascending order
+pub fn days(year: u16) -> Vec<u8> {
+    implementations()
+        .filter(|implementation| implementation.year == year)
+        .map(|implementation| implementation.day)
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// Finds the implementation called `name` of a part, falling back to the
+/// primary one if there is no such alternative
+pub fn find(year: u16, day: u8, part: Part, name: Option<&str>) -> Option<&'static Implementation> {
+    let candidates = implementations()
+        .filter(|i| i.year == year && i.day == day && i.part == part)
+        .collect::<Vec<_>>();
+    name.and_then(|name| candidates.iter().find(|i| i.name == name))
+        .or_else(|| candidates.iter().find(|i| i.primary))
+        .copied()
+}
+
+/// The sample to run `part` of a day on. Only samples with an expected answer
+/// for the part are considered, as solutions often make assumptions about the
+/// input that samples meant for the other part don't fulfill.
+pub fn sample(year: u16, day: u8, part: Part) -> Option<&'static Sample> {
+    inventory::iter::<Sample>()
+        .find(|sample| sample.year == year && sample.day == day && sample.expected(part).is_some())
+}
+
+/// Alternative implementations, sorted by year, day, part and name
+pub fn alternatives() -> Vec<&'static Implementation> {
+    implementations()
+        .filter(|implementation| !implementation.primary)
+        .sorted_by_key(|i| (i.year, i.day, i.part, i.name))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_all_days_registered() {
+        let disabled = crate::solutions::disabled_days(2023);
+        let enabled = (1..=25)
+            .filter(|day| !disabled.contains(day))
+            .collect::<Vec<_>>();
+        assert_eq!(days(2023), enabled);
+        for day in enabled {
+            for part in Part::ALL {
+                // The last day only has one puzzle
+                if (day, part) == (25, Part::Two) {
+                    assert!(find(2023, day, part, None).is_none());
+                    continue;
+                }
+                let implementation = find(2023, day, part, None).unwrap();
+                assert!(implementation.primary);
+                assert_eq!(implementation.name, "default");
+            }
+        }
+    }
+
+    #[test]
+    fn test_samples() {
+        for sample in inventory::iter::<Sample>() {
+            for part in Part::ALL {
+                let Some(expected) = sample.expected(part) else {
+                    continue;
+                };
+                let implementation = find(sample.year, sample.day, part, None).unwrap();
+                let answer = (implementation.run)(sample.input).unwrap().answer;
+                assert_eq!(
+                    answer, expected,
+                    "{} day {} part {part}",
+                    sample.year, sample.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "y2023-day-06")]
+    fn test_find_alternative() {
+        assert_eq!(
+            find(2023, 6, Part::Two, Some("bisect")).unwrap().name,
+            "bisect"
+        );
+        assert_eq!(
+            find(2023, 6, Part::One, Some("bisect")).unwrap().name,
+            "default"
+        );
+        assert!(find(2023, 26, Part::One, None).is_none());
+    }
+}
@@ -0,0 +1,628 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    io,
+    ops::RangeInclusive,
+    panic,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use clap::{ArgGroup, Args, ValueEnum};
+use itertools::Itertools;
+
+use crate::{
+    answer::Answer,
+    config::{self, ApplyConfig, Config, InputDirArg, YearArg},
+    input::{self, Downloader},
+    output,
+    parse::ParseError,
+    registry::{self, Implementation, Timed},
+    solutions,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The answer, or why there is none
+    pub outcome: Result<Timed, String>,
+}
+
+impl PartResult {
+    pub fn answer(&self) -> Option<&Answer> {
+        self.outcome.as_ref().ok().map(|timed| &timed.answer)
+    }
+
+    /// Time it took to get from the raw input to the answer, i.e. the time
+    /// spent parsing plus the time spent solving this part
+    pub fn elapsed(&self) -> Option<Duration> {
+        let timed = self.outcome.as_ref().ok()?;
+        Some(timed.parse_time + timed.solve_time)
+    }
+}
+
+/// Describes the payload of a caught panic
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown cause".to_string(),
+    }
+}
+
+/// A set of days, as given on the command line, e.g. `3,7,12-15`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<RangeInclusive<u8>>);
+
+impl DaySelection {
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self.0.iter().cloned().flatten().collect::<Vec<_>>();
+        days.sort();
+        days.dedup();
+        days
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .map_err(|err| format!("invalid day '{day}': {err}"))
+        };
+        s.split(',')
+            .map(|item| match item.split_once('-') {
+                Some((start, end)) => {
+                    let range = parse_day(start)?..=parse_day(end)?;
+                    if range.is_empty() {
+                        Err(format!("empty range of days '{item}'"))
+                    } else {
+                        Ok(range)
+                    }
+                }
+                None => parse_day(item).map(|day| day..=day),
+            })
+            .collect::<Result<_, _>>()
+            .map(DaySelection)
+    }
+}
+
+/// Formats a sorted list of days compactly, e.g. `1-3, 5, 7-8`
+pub fn format_days(days: &[u8]) -> String {
+    days.chunk_by(|a, b| a + 1 == *b)
+        .map(|run| match run {
+            [day] => day.to_string(),
+            [first, .., last] => format!("{first}-{last}"),
+            [] => unreachable!(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parses a timeout given as a positive number of seconds, e.g. `2.5`
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let secs = s.parse::<f64>().map_err(|err| err.to_string())?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        Ok(_) => Err("the timeout has to be more than 0 seconds".to_string()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Makes sure that at least one of `parts` of `days` has an implementation
+/// called `name`, the others fall back to their primary one
+fn check_implementation(year: u16, days: &[u8], parts: &[Part], name: &str) -> Result<(), String> {
+    let found = registry::implementations().any(|implementation| {
+        implementation.year == year
+            && days.contains(&implementation.day)
+            && parts.contains(&implementation.part)
+            && implementation.name == name
+    });
+    match found {
+        true => Ok(()),
+        false => Err(format!(
+            "none of the selected parts has an implementation called `{name}`, \
+             `--list` shows the alternatives"
+        )),
+    }
+}
+
+/// Makes sure there is a solution for each of `days` in `year`
+fn check_available(year: u16, days: &[u8]) -> Result<(), String> {
+    let available = registry::days(year);
+    let disabled = solutions::disabled_days(year);
+    // Selecting all days of a build without any of them selects none
+    let days = match (days, available.is_empty()) {
+        ([], true) => &disabled,
+        _ => days,
+    };
+    let unavailable = days
+        .iter()
+        .filter(|day| disabled.contains(day))
+        .map(|&day| crate::Error::Unavailable { year, day }.to_string())
+        .collect::<Vec<_>>();
+    if !unavailable.is_empty() {
+        return Err(unavailable.join("\n"));
+    }
+    if available.is_empty() {
+        let years = solutions::YEARS.iter().map(u16::to_string).join(", ");
+        return Err(format!(
+            "there are no solutions for {year}, available years are {years}"
+        ));
+    }
+    match days.iter().find(|day| !available.contains(day)) {
+        Some(day) => Err(format!(
+            "there is no solution for day {day} of {year}, available days are {}",
+            format_days(&available)
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Which days, parts and inputs to run, shared by all commands that run solutions
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "days", "all"])))]
+pub struct Selection {
+    #[command(flatten)]
+    year: YearArg,
+
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Comma separated list of days and ranges of days, e.g. `3,7,12-15`
+    #[arg(long)]
+    days: Option<DaySelection>,
+
+    /// Run every day
+    #[arg(long)]
+    all: bool,
+
+    /// Only run this part of each day
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Use the alternative implementation with this name for the parts that have one
+    #[arg(long = "impl")]
+    implementation: Option<String>,
+
+    /// Puzzle input to use, or `-` to read it from stdin. Only valid for a single day
+    #[arg(long, conflicts_with_all = ["days", "all"])]
+    input: Option<PathBuf>,
+
+    /// Run each part on the sample from the puzzle description instead of the input
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+
+    #[command(flatten)]
+    input_dir: InputDirArg,
+
+    /// Server to download missing inputs from and submit answers to
+    /// [default: https://adventofcode.com]
+    #[arg(long)]
+    endpoint: Option<String>,
+
+    /// Give up on a part after this many seconds. The solution keeps running in
+    /// the background until all other days are done, so `watch` doesn't accept
+    /// this.
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Session cookie from the config, which isn't accepted as a flag to keep
+    /// it out of the shell history
+    #[arg(skip)]
+    session: Option<String>,
+}
+
+impl ApplyConfig for Selection {
+    fn apply(&mut self, config: &Config) {
+        self.year.apply(config);
+        self.input_dir.apply(config);
+        self.endpoint = self.endpoint.take().or_else(|| config.endpoint.clone());
+        self.session = config.session.clone();
+    }
+}
+
+impl Selection {
+    pub fn year(&self) -> u16 {
+        self.year.year()
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        match (self.day, &self.days) {
+            (Some(day), _) => vec![day],
+            (None, Some(selection)) => selection.days(),
+            (None, None) => registry::days(self.year()),
+        }
+    }
+
+    /// Checks that all selected days have a solution, and that `--impl` names
+    /// an implementation of at least one selected part. This can't be done
+    /// while parsing the individual arguments, as it depends on the year.
+    pub fn validate(&self) -> Result<(), String> {
+        let (year, days) = (self.year(), self.days());
+        check_available(year, &days)?;
+        match self.implementation() {
+            Some(name) => check_implementation(year, &days, &self.parts(), name),
+            None => Ok(()),
+        }
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    pub fn implementation(&self) -> Option<&str> {
+        self.implementation.as_deref()
+    }
+
+    pub fn example(&self) -> bool {
+        self.example
+    }
+
+    pub fn endpoint(&self) -> &str {
+        self.endpoint.as_deref().unwrap_or(input::DEFAULT_ENDPOINT)
+    }
+
+    pub fn session(&self) -> Option<String> {
+        input::session_token(self.session.as_deref())
+    }
+
+    /// Input directory given as a flag or read from the config, if any
+    pub fn input_dir(&self) -> Option<&Path> {
+        self.input_dir.given()
+    }
+
+    /// Whether a single input file was given with `--input`
+    pub fn has_input(&self) -> bool {
+        self.input.is_some()
+    }
+
+    pub fn has_timeout(&self) -> bool {
+        self.timeout.is_some()
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        match &self.input {
+            Some(path) => path.clone(),
+            None => input::default_path(self.input_dir.dir(), self.year(), day),
+        }
+    }
+
+    /// Reads the input for `day`, downloading it if it isn't available locally.
+    /// A failure is reported to stderr.
+    pub fn read_input(&self, day: u8) -> io::Result<DayInput> {
+        if self.example {
+            return Ok(DayInput::Samples {
+                year: self.year(),
+                day,
+            });
+        }
+        let path = self.input_path(day);
+        let downloader = self.session().map(|session| Downloader {
+            endpoint: self.endpoint().to_string(),
+            session,
+        });
+        let result = match self.input {
+            Some(_) => input::read_input(&path),
+            None => input::cached_input(&path, self.year(), day, downloader.as_ref()),
+        };
+        result.map(DayInput::Input).inspect_err(|err| {
+            eprintln!(
+                "Skipping day {day} of {}, could not read {}: {err}",
+                self.year(),
+                path.display()
+            );
+            if downloader.is_none() && err.kind() == io::ErrorKind::NotFound {
+                eprintln!(
+                    "Set {}, `session` in {} or save your session cookie in {} to download missing inputs",
+                    input::SESSION_VAR,
+                    config::FILE_NAME,
+                    input::session_file().unwrap_or_default().display()
+                );
+            }
+        })
+    }
+}
+
+/// Input of each part of a day, which only differs between parts for samples
+pub enum DayInput {
+    Input(String),
+    Samples { year: u16, day: u8 },
+}
+
+impl DayInput {
+    pub fn for_part(&self, part: Part) -> Option<&str> {
+        match self {
+            DayInput::Input(input) => Some(input),
+            DayInput::Samples { year, day } => {
+                registry::sample(*year, *day, part).map(|sample| sample.input)
+            }
+        }
+    }
+}
+
+/// Runs all selected days. Parts that fail, e.g. because their input can't be
+/// read or their solution panics, are reported as failed results, which is
+/// also signalled by returning `false`.
+pub fn run(selection: &Selection) -> (Vec<PartResult>, bool) {
+    let year = selection.year();
+    let parts = selection.parts();
+    let mut results = vec![];
+    for day in selection.days() {
+        match selection.read_input(day) {
+            Ok(input) => results.extend(run_day(selection, day, &input)),
+            Err(err) => results.extend(parts.iter().map(|&part| PartResult {
+                year,
+                day,
+                part,
+                outcome: Err(format!("could not read the input: {err}")),
+            })),
+        }
+    }
+    let success = results.iter().all(|result| result.outcome.is_ok());
+    (results, success)
+}
+
+/// Runs the selected parts of `day` on `input`
+pub fn run_day(selection: &Selection, day: u8, input: &DayInput) -> Vec<PartResult> {
+    let year = selection.year();
+    let mut results = vec![];
+    let mut reported = None;
+    for part in selection.parts() {
+        // Not every part has a sample, which isn't worth failing over
+        let Some(input) = input.for_part(part) else {
+            eprintln!("Skipping day {day} of {year}, part {part} has no sample");
+            continue;
+        };
+        // Only a part that was asked for counts as failed when it has no
+        // solution, e.g. part 2 of the last day
+        let Some(implementation) = registry::find(year, day, part, selection.implementation())
+        else {
+            eprintln!("Skipping day {day} of {year}, part {part} has no solution");
+            if selection.part.is_some() {
+                results.push(PartResult {
+                    year,
+                    day,
+                    part,
+                    outcome: Err("no solution".to_string()),
+                });
+            }
+            continue;
+        };
+        let outcome = run_part(
+            implementation,
+            year,
+            day,
+            part,
+            input,
+            selection,
+            &mut reported,
+        );
+        results.push(PartResult {
+            year,
+            day,
+            part,
+            outcome,
+        });
+    }
+    results
+}
+
+fn run_part(
+    implementation: &Implementation,
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &str,
+    selection: &Selection,
+    reported: &mut Option<String>,
+) -> Result<Timed, String> {
+    match run_isolated(implementation.run, input, selection.timeout) {
+        Ok(Ok(timed)) => Ok(timed),
+        Ok(Err(err)) => {
+            let err = err.for_day(day);
+            // Both parts parse the same input, so they usually fail the same way
+            let message = format!("Invalid input for {year} {err}\n{}", err.diagnostic());
+            if reported.as_ref() != Some(&message) {
+                eprint!("{message}");
+            }
+            *reported = Some(message);
+            Err(format!("invalid input, {err}"))
+        }
+        Err(failure) => {
+            eprintln!("Day {day} of {year}, part {part} {failure}");
+            Err(failure.to_string())
+        }
+    }
+}
+
+/// Why a solution didn't produce an answer or a parse error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+            Failure::TimedOut(timeout) => {
+                write!(f, "timed out after {}", output::format_duration(*timeout))
+            }
+        }
+    }
+}
+
+/// Runs `solve` on its own thread, so that a panic or a solution that takes
+/// longer than `timeout` doesn't take down the other days. A thread that
+/// times out can't be stopped, it is left running in the background.
+pub fn run_isolated(
+    solve: fn(&str) -> Result<Timed, ParseError>,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Result<Result<Timed, ParseError>, Failure> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || {
+        let outcome = panic::catch_unwind(|| solve(&input))
+            .map_err(|payload| Failure::Panicked(panic_message(&*payload)));
+        // The receiver is gone if this timed out, which is fine to ignore
+        let _ = sender.send(outcome);
+    });
+    match timeout {
+        Some(timeout) => receiver
+            .recv_timeout(timeout)
+            .unwrap_or(Err(Failure::TimedOut(timeout))),
+        // The sender is only dropped without sending if the thread panicked
+        // while unwinding, which aborts the process anyway
+        None => receiver
+            .recv()
+            .expect("Solution thread stopped without a result"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_day_selection() {
+        let selection: DaySelection = "3,7,12-15".parse().unwrap();
+        assert_eq!(selection.days(), vec![3, 7, 12, 13, 14, 15]);
+
+        let overlapping: DaySelection = "5-6,1,6".parse().unwrap();
+        assert_eq!(overlapping.days(), vec![1, 5, 6]);
+
+        assert!("".parse::<DaySelection>().is_err());
+        assert!("3,x".parse::<DaySelection>().is_err());
+        assert!("7-3".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("NaN").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "all-days")]
+    fn test_check_available() {
+        assert_eq!(check_available(2023, &[1, 5, 25]), Ok(()));
+        assert_eq!(
+            check_available(2023, &[5, 30]),
+            Err("there is no solution for day 30 of 2023, available days are 1-25".to_string())
+        );
+        assert_eq!(
+            check_available(1999, &[1]),
+            Err("there are no solutions for 1999, available years are 2023".to_string())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "y2023-day-06")]
+    fn test_check_implementation() {
+        assert_eq!(
+            check_implementation(2023, &[5, 6], &Part::ALL, "bisect"),
+            Ok(())
+        );
+        assert_eq!(
+            check_implementation(2023, &[6], &[Part::One], "default"),
+            Ok(())
+        );
+        assert_eq!(
+            check_implementation(2023, &[6], &[Part::One], "bisect"),
+            Err(
+                "none of the selected parts has an implementation called `bisect`, \
+                 `--list` shows the alternatives"
+                    .to_string()
+            )
+        );
+        assert!(check_implementation(2023, &[6], &Part::ALL, "nope").is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "y2023-day-05"))]
+    fn test_check_unavailable() {
+        assert_eq!(
+            check_available(2023, &[5]),
+            Err(crate::Error::Unavailable { year: 2023, day: 5 }.to_string())
+        );
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        let timeout = Some(Duration::from_millis(100));
+        let answer = |_: &str| {
+            Ok(Timed {
+                answer: Answer::from(42),
+                parse_time: Duration::ZERO,
+                solve_time: Duration::ZERO,
+            })
+        };
+        assert_eq!(
+            run_isolated(answer, "", timeout).unwrap().unwrap().answer,
+            Answer::from(42)
+        );
+        assert_eq!(
+            run_isolated(|_| panic!("No rock found :("), "", timeout).unwrap_err(),
+            Failure::Panicked("No rock found :(".to_string())
+        );
+        let spin = |_: &str| loop {
+            thread::sleep(Duration::from_millis(10));
+        };
+        assert_eq!(
+            run_isolated(spin, "", timeout).unwrap_err().to_string(),
+            "timed out after 100.00ms"
+        );
+    }
+
+    #[test]
+    fn test_format_days() {
+        assert_eq!(format_days(&[1, 2, 3, 5, 7, 8]), "1-3, 5, 7-8");
+        assert_eq!(format_days(&[4]), "4");
+        assert_eq!(format_days(&[]), "");
+    }
+}
@@ -0,0 +1,337 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+use indoc::indoc;
+
+use crate::{
+    config::{ApplyConfig, Config, InputDirArg, YearArg},
+    input, solutions,
+};
+
+#[derive(Args)]
+pub struct NewArgs {
+    #[command(flatten)]
+    year: YearArg,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Root of the crate in which the day should be created
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+
+    #[command(flatten)]
+    input_dir: InputDirArg,
+}
+
+impl ApplyConfig for NewArgs {
+    fn apply(&mut self, config: &Config) {
+        self.year.apply(config);
+        self.input_dir.apply(config);
+    }
+}
+
+/// Source of a new day, with `{year}` and `{day}` as placeholders. The sample
+/// is registered like in the other days, so that `--example` and the tests
+/// check it once its input and answer are filled in.
+const TEMPLATE: &str = indoc! {r#"
+    use aoc::aoc;
+    use indoc::indoc;
+
+    use crate::{parse::ParseError, solutions::Solution};
+
+    pub struct Solver;
+
+    #[aoc(year = {year}, day = {day})]
+    impl Solution for Solver {
+        type Parsed = String;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(input.to_string())
+        }
+
+        fn part1(_input: &Self::Parsed) -> Self::Part1 {
+            todo!()
+        }
+
+        fn part2(_input: &Self::Parsed) -> Self::Part2 {
+            todo!()
+        }
+    }
+
+    #[aoc(year = {year}, day = {day}, part1 = 0)]
+    const SAMPLE: &str = indoc! {"
+    "};
+"#};
+
+fn year_module_name(year: u16) -> String {
+    format!("year_{year}")
+}
+
+fn module_name(day: u8) -> String {
+    format!("day_{day:02}")
+}
+
+fn day_entry(year: u16, day: u8) -> String {
+    let feature = solutions::feature(year, day);
+    format!("{day} => {}: \"{feature}\",", module_name(day))
+}
+
+fn year_entry(year: u16) -> String {
+    format!("{year} => {},", year_module_name(year))
+}
+
+/// Adds `entry` to the `days!` or `years!` list in `parent`, keeping it sorted
+/// by the number that each entry starts with, e.g. `3 => day_03: "y2023-day-03",`
+fn add_entry(parent: &str, number: u16, entry: &str) -> Option<String> {
+    let entry_number = |line: &&str| {
+        line.trim_start()
+            .split_once(" => ")
+            .and_then(|(number, _)| number.parse::<u16>().ok())
+    };
+    let mut lines = parent.lines().collect::<Vec<_>>();
+    let position = lines
+        .iter()
+        .position(|line| entry_number(line).is_some_and(|other| other > number))
+        .or_else(|| {
+            let last = lines.iter().rposition(|line| entry_number(line).is_some());
+            last.map(|last| last + 1)
+        })
+        .or_else(|| {
+            let open = lines.iter().position(|line| line.ends_with("! {"));
+            open.map(|open| open + 1)
+        })?;
+    let line = format!("    {entry}");
+    lines.insert(position, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the feature of `day` in `year` to the features in `manifest`, and to
+/// the ones enabled by `all-days`
+fn add_day_feature(manifest: &str, year: u16, day: u8) -> Option<String> {
+    let feature = solutions::feature(year, day);
+    let declaration = format!("{feature} = []");
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+    let all_days = lines
+        .iter()
+        .position(|line| line.starts_with("all-days = ["))?;
+    let mut features = lines[all_days]
+        .trim_start_matches("all-days = [")
+        .trim_end_matches(']')
+        .split(',')
+        .map(|feature| feature.trim().trim_matches('"').to_string())
+        .filter(|feature| !feature.is_empty())
+        .collect::<Vec<_>>();
+    if !features.contains(&feature) {
+        features.push(feature);
+        features.sort();
+    }
+    let features = features.iter().map(|feature| format!("\"{feature}\""));
+    lines[all_days] = format!("all-days = [{}]", features.collect::<Vec<_>>().join(", "));
+
+    if !lines.contains(&declaration) {
+        let is_day = |line: &String| line.starts_with('y') && line.contains("-day-");
+        let position = lines
+            .iter()
+            .position(|line| is_day(line) && *line > declaration)
+            .or_else(|| lines.iter().rposition(is_day).map(|last| last + 1))
+            .unwrap_or(all_days + 1);
+        lines.insert(position, declaration);
+    }
+    Some(lines.join("\n") + "\n")
+}
+
+fn create_new(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    println!("Created {}", path.display());
+    Ok(())
+}
+
+/// Adds `entry` to the `list!` in `parent_path`, creating it if needed.
+/// Returns whether the parent had to be created.
+fn declare_module(parent_path: &Path, list: &str, number: u16, entry: &str) -> io::Result<bool> {
+    if parent_path.exists() {
+        let parent = fs::read_to_string(parent_path)?;
+        let updated = add_entry(&parent, number, entry).ok_or_else(|| {
+            let message = format!("{} has no `{list}!` list", parent_path.display());
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        })?;
+        fs::write(parent_path, updated)?;
+        println!("Updated {}", parent_path.display());
+        Ok(false)
+    } else {
+        create_new(parent_path, &format!("{list}! {{\n    {entry}\n}}\n"))?;
+        Ok(true)
+    }
+}
+
+/// Adds the feature of `day` in `year` to the manifest at `path`
+fn declare_feature(path: &Path, year: u16, day: u8) -> io::Result<()> {
+    let manifest = fs::read_to_string(path)?;
+    let updated = add_day_feature(&manifest, year, day).ok_or_else(|| {
+        let message = format!("{} has no `all-days` feature", path.display());
+        io::Error::new(io::ErrorKind::InvalidData, message)
+    })?;
+    if updated != manifest {
+        fs::write(path, updated)?;
+        println!("Updated {}", path.display());
+    }
+    Ok(())
+}
+
+fn scaffold(args: &NewArgs, year: u16) -> io::Result<()> {
+    let solutions_dir = args.root.join("src").join("solutions");
+    let year_path = solutions_dir.join(format!("{}.rs", year_module_name(year)));
+    let module_path = solutions_dir
+        .join(year_module_name(year))
+        .join(format!("{}.rs", module_name(args.day)));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    let source = TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &args.day.to_string());
+    create_new(&module_path, &source)?;
+    if declare_module(
+        &year_path,
+        "days",
+        args.day.into(),
+        &day_entry(year, args.day),
+    )? {
+        declare_module(
+            &args.root.join("src").join("solutions.rs"),
+            "years",
+            year,
+            &year_entry(year),
+        )?;
+    }
+    declare_feature(&args.root.join("Cargo.toml"), year, args.day)?;
+
+    let input_path = input::default_path(args.input_dir.dir(), year, args.day);
+    if !input_path.exists() {
+        create_new(&input_path, "")?;
+    }
+    Ok(())
+}
+
+pub fn new_day(args: NewArgs) -> bool {
+    let year = args.year.year();
+    scaffold(&args, year)
+        .inspect_err(|err| eprintln!("Could not create day {} of {year}: {err}", args.day))
+        .is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const SOLUTIONS: &str = indoc! {"
+        use crate::answer::Answer;
+
+        years! {
+            2023 => year_2023,
+        }
+
+        pub trait Solution {}
+    "};
+
+    const YEAR: &str = indoc! {r#"
+        days! {
+            1 => day_01: "y2023-day-01",
+            3 => day_03: "y2023-day-03",
+        }
+    "#};
+
+    #[test]
+    fn test_add_entry() {
+        assert_eq!(
+            add_entry(SOLUTIONS, 2015, &year_entry(2015)).unwrap(),
+            indoc! {"
+                use crate::answer::Answer;
+
+                years! {
+                    2015 => year_2015,
+                    2023 => year_2023,
+                }
+
+                pub trait Solution {}
+            "}
+        );
+        assert_eq!(
+            add_entry(YEAR, 2, &day_entry(2023, 2)).unwrap(),
+            indoc! {r#"
+                days! {
+                    1 => day_01: "y2023-day-01",
+                    2 => day_02: "y2023-day-02",
+                    3 => day_03: "y2023-day-03",
+                }
+            "#}
+        );
+        assert_eq!(
+            add_entry(YEAR, 10, &day_entry(2023, 10)).unwrap(),
+            indoc! {r#"
+                days! {
+                    1 => day_01: "y2023-day-01",
+                    3 => day_03: "y2023-day-03",
+                    10 => day_10: "y2023-day-10",
+                }
+            "#}
+        );
+        assert_eq!(
+            add_entry("days! {\n}\n", 4, &day_entry(2023, 4)).unwrap(),
+            "days! {\n    4 => day_04: \"y2023-day-04\",\n}\n"
+        );
+        assert_eq!(add_entry("pub mod day_01;\n", 4, &day_entry(2023, 4)), None);
+    }
+
+    #[test]
+    fn test_add_day_feature() {
+        let manifest = indoc! {r#"
+            [features]
+            default = ["all-days"]
+            all-days = ["y2023-day-01", "y2023-day-03"]
+            y2023-day-01 = []
+            y2023-day-03 = []
+            serve = []
+        "#};
+        assert_eq!(
+            add_day_feature(manifest, 2023, 2).unwrap(),
+            indoc! {r#"
+                [features]
+                default = ["all-days"]
+                all-days = ["y2023-day-01", "y2023-day-02", "y2023-day-03"]
+                y2023-day-01 = []
+                y2023-day-02 = []
+                y2023-day-03 = []
+                serve = []
+            "#}
+        );
+        assert_eq!(add_day_feature(manifest, 2023, 3).unwrap(), manifest);
+        assert_eq!(
+            add_day_feature(manifest, 2024, 1).unwrap(),
+            indoc! {r#"
+                [features]
+                default = ["all-days"]
+                all-days = ["y2023-day-01", "y2023-day-03", "y2024-day-01"]
+                y2023-day-01 = []
+                y2023-day-03 = []
+                y2024-day-01 = []
+                serve = []
+            "#}
+        );
+        assert_eq!(add_day_feature("[features]\n", 2023, 3), None);
+    }
+}
@@ -0,0 +1,391 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use clap::Args;
+use serde_json::{json, Value};
+
+use crate::{
+    config::{ApplyConfig, Config, YearArg},
+    output::Record,
+    registry,
+    runner::{self, Failure, Part, PartResult},
+};
+
+/// Serves the solutions over HTTP:
+///
+/// - `POST /solve/{day}/{part}` with the input as body returns the answer and
+///   timings, or the error, in the same format as `--format json`
+/// - `GET /days` returns the days with a solution per year
+#[derive(Args, Clone)]
+pub struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    address: String,
+
+    #[command(flatten)]
+    year: YearArg,
+
+    /// Largest accepted input, in bytes
+    #[arg(long, default_value_t = 1 << 20)]
+    max_input: usize,
+
+    /// Give up on a request after this many seconds
+    #[arg(long, default_value = "10", value_parser = runner::parse_timeout)]
+    timeout: Duration,
+
+    /// Number of requests handled at the same time, more are turned away. A
+    /// request that timed out counts until its solution is done.
+    #[arg(long, default_value_t = 8)]
+    max_requests: usize,
+}
+
+impl ApplyConfig for ServeArgs {
+    fn apply(&mut self, config: &Config) {
+        self.year.apply(config);
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: &'static str,
+    body: Value,
+}
+
+impl Response {
+    fn error(status: &'static str, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Response {
+            status,
+            body: json!({ "error": message }),
+        }
+    }
+
+    fn write_to(&self, stream: &mut impl Write) -> io::Result<()> {
+        let body = self.body.to_string();
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.status,
+            body.len()
+        )
+    }
+}
+
+/// Largest accepted request line and headers together, in bytes
+const MAX_HEAD: u64 = 16 * 1024;
+
+/// Reads a line of the request line and headers from `head`, which stops at
+/// `MAX_HEAD` bytes
+fn read_head_line(head: &mut io::Take<impl BufRead>, line: &mut String) -> Result<(), Response> {
+    line.clear();
+    head.read_line(line)
+        .map_err(|_| Response::error("400 Bad Request", "malformed request"))?;
+    if !line.ends_with('\n') && head.limit() == 0 {
+        return Err(Response::error(
+            "431 Request Header Fields Too Large",
+            format!("the request line and headers can be at most {MAX_HEAD} bytes"),
+        ));
+    }
+    Ok(())
+}
+
+fn read_request(reader: &mut impl BufRead, max_input: usize) -> Result<Request, Response> {
+    let bad_request = |_| Response::error("400 Bad Request", "malformed request");
+    let mut head = reader.take(MAX_HEAD);
+    let mut line = String::new();
+    read_head_line(&mut head, &mut line)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(path)) = (words.next(), words.next()) else {
+        return Err(Response::error("400 Bad Request", "malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        read_head_line(&mut head, &mut line)?;
+        if line.is_empty() {
+            return Err(Response::error("400 Bad Request", "incomplete headers"));
+        }
+        match line.split_once(':') {
+            Some((name, value)) if name.eq_ignore_ascii_case("content-length") => {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Response::error("400 Bad Request", "invalid Content-Length"))?;
+            }
+            None if line.trim().is_empty() => break,
+            _ => (),
+        }
+    }
+    if content_length > max_input {
+        return Err(Response::error(
+            "413 Payload Too Large",
+            format!("inputs can be at most {max_input} bytes"),
+        ));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(bad_request)?;
+    Ok(Request { method, path, body })
+}
+
+fn days() -> Response {
+    let days = registry::years()
+        .into_iter()
+        .map(|year| (year.to_string(), registry::days(year)))
+        .collect::<BTreeMap<_, _>>();
+    Response {
+        status: "200 OK",
+        body: json!(days),
+    }
+}
+
+fn solve(args: &ServeArgs, slot: &Arc<Slot>, day: &str, part: &str, body: Vec<u8>) -> Response {
+    let not_found = || Response::error("404 Not Found", format!("no solution for {day}/{part}"));
+    let year = args.year.year();
+    let Ok(day) = day.parse::<u8>() else {
+        return not_found();
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return not_found(),
+    };
+    let Some(implementation) = registry::find(year, day, part, None) else {
+        return not_found();
+    };
+    let Ok(input) = String::from_utf8(body) else {
+        return Response::error("400 Bad Request", "the input has to be UTF-8");
+    };
+
+    // A solution that times out can't be stopped, so it holds on to the slot
+    // of its request until it is done, to not run more than `max_requests`
+    let (sender, receiver) = mpsc::channel();
+    let slot = Arc::clone(slot);
+    thread::spawn(move || {
+        let _slot = slot;
+        // The receiver is gone if this timed out, which is fine to ignore
+        let _ = sender.send(runner::run_isolated(implementation.run, &input, None));
+    });
+    let result = receiver
+        .recv_timeout(args.timeout)
+        .unwrap_or(Err(Failure::TimedOut(args.timeout)));
+    let (status, outcome) = match result {
+        Ok(Ok(timed)) => ("200 OK", Ok(timed)),
+        Ok(Err(err)) => (
+            "422 Unprocessable Entity",
+            Err(err.for_day(day).to_string()),
+        ),
+        Err(failure @ Failure::TimedOut(_)) => ("504 Gateway Timeout", Err(failure.to_string())),
+        Err(failure) => ("500 Internal Server Error", Err(failure.to_string())),
+    };
+    let result = PartResult {
+        year,
+        day,
+        part,
+        outcome,
+    };
+    Response {
+        status,
+        body: json!(Record::from(&result)),
+    }
+}
+
+fn route(args: &ServeArgs, slot: &Arc<Slot>, request: Request) -> Response {
+    let segments = request.path.split('/').skip(1).collect::<Vec<_>>();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => days(),
+        ("POST", ["solve", day, part]) => solve(args, slot, day, part, request.body),
+        (_, ["days"] | ["solve", _, _]) => {
+            Response::error("405 Method Not Allowed", "method not allowed")
+        }
+        _ => Response::error("404 Not Found", format!("unknown path {}", request.path)),
+    }
+}
+
+fn handle(args: &ServeArgs, slot: &Arc<Slot>, mut stream: TcpStream) -> io::Result<()> {
+    // Don't let a client that never finishes its request hold on to a slot
+    stream.set_read_timeout(Some(args.timeout))?;
+    let mut reader = BufReader::new(&stream);
+    let response = match read_request(&mut reader, args.max_input) {
+        Ok(request) => route(args, slot, request),
+        Err(response) => response,
+    };
+    response.write_to(&mut stream)
+}
+
+/// One of the `max_requests` requests handled at the same time, given back
+/// when dropped
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    fn acquire(active: &Arc<AtomicUsize>, max_requests: usize) -> Option<Slot> {
+        let taken = active.fetch_add(1, Ordering::SeqCst);
+        // Dropping the slot right away gives back the one just taken
+        let slot = Slot(Arc::clone(active));
+        (taken < max_requests).then_some(slot)
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Handles the connections to `listener`, each on its own thread
+fn accept(args: ServeArgs, listener: TcpListener) {
+    let args = Arc::new(args);
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        let Some(slot) = Slot::acquire(&active, args.max_requests) else {
+            let busy = Response::error("503 Service Unavailable", "too many requests");
+            let _ = busy.write_to(&mut stream);
+            continue;
+        };
+        let args = Arc::clone(&args);
+        let slot = Arc::new(slot);
+        thread::spawn(move || {
+            if let Err(err) = handle(&args, &slot, stream) {
+                eprintln!("Failed to handle a request: {err}");
+            }
+        });
+    }
+}
+
+pub fn serve(args: ServeArgs) -> bool {
+    let listener = match TcpListener::bind(&args.address) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("Could not listen on {}: {err}", args.address);
+            return false;
+        }
+    };
+    println!("Listening on http://{}", args.address);
+    accept(args, listener);
+    true
+}
+
+#[cfg(all(test, feature = "y2023-day-06"))]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::Read;
+
+    fn start(max_input: usize) -> String {
+        let args = ServeArgs {
+            address: "127.0.0.1:0".to_string(),
+            year: YearArg { year: Some(2023) },
+            max_input,
+            timeout: Duration::from_secs(5),
+            max_requests: 4,
+        };
+        let listener = TcpListener::bind(&args.address).unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || accept(args, listener));
+        address
+    }
+
+    fn request(address: &str, method: &str, path: &str, body: &str) -> (String, Value) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.lines().next().unwrap().split_once(' ').unwrap().1;
+        (status.to_string(), serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn test_slots() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let first = Slot::acquire(&active, 2).unwrap();
+        let second = Slot::acquire(&active, 2).unwrap();
+        assert!(Slot::acquire(&active, 2).is_none());
+        drop(first);
+        let third = Slot::acquire(&active, 2).unwrap();
+        drop((second, third));
+        assert_eq!(active.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_serve() {
+        let address = start(1000);
+        let sample = registry::sample(2023, 6, Part::One).unwrap().input;
+
+        let (status, days) = request(&address, "GET", "/days", "");
+        assert_eq!(status, "200 OK");
+        assert_eq!(
+            days["2023"].as_array().unwrap().len(),
+            registry::days(2023).len()
+        );
+
+        let (status, solved) = request(&address, "POST", "/solve/6/1", sample);
+        assert_eq!(status, "200 OK");
+        assert_eq!(solved["answer"], "288");
+        assert_eq!(solved["status"], "ok");
+
+        let (status, invalid) = request(&address, "POST", "/solve/6/2", "Time: x");
+        assert_eq!(status, "422 Unprocessable Entity");
+        assert_eq!(invalid["status"], "error");
+
+        let (status, _) = request(&address, "POST", "/solve/26/1", sample);
+        assert_eq!(status, "404 Not Found");
+        let (status, _) = request(&address, "GET", "/solve/6/1", "");
+        assert_eq!(status, "405 Method Not Allowed");
+        let (status, _) = request(&address, "POST", "/solve/6/1", &"1".repeat(1001));
+        assert_eq!(status, "413 Payload Too Large");
+    }
+
+    #[test]
+    fn test_read_request_head_limit() {
+        let status = |request: String| match read_request(&mut request.as_bytes(), 1000) {
+            Ok(request) => format!("{} {}", request.method, request.path),
+            Err(response) => response.status.to_string(),
+        };
+        let header = |len| format!("X-Padding: {}\r\n", "a".repeat(len));
+
+        let fits = header(MAX_HEAD as usize - 100);
+        assert_eq!(
+            status(format!("GET /days HTTP/1.1\r\n{fits}\r\n")),
+            "GET /days"
+        );
+        let too_long = header(MAX_HEAD as usize);
+        assert_eq!(
+            status(format!("GET /days HTTP/1.1\r\n{too_long}\r\n")),
+            "431 Request Header Fields Too Large"
+        );
+        assert_eq!(
+            status(format!(
+                "GET /{} HTTP/1.1\r\n\r\n",
+                "a".repeat(MAX_HEAD as usize)
+            )),
+            "431 Request Header Fields Too Large"
+        );
+        assert_eq!(
+            status("GET /days HTTP/1.1\r\n".to_string()),
+            "400 Bad Request"
+        );
+    }
+}
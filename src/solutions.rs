@@ -1,25 +1,67 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_19;
-pub mod day_20;
-pub mod day_21;
-pub mod day_22;
-pub mod day_23;
-pub mod day_24;
-pub mod day_25;
+use crate::{answer::Answer, parse::ParseError};
+
+/// Declares the module of each day of a year, each only built with its feature
+/// (see [`feature`]), along with `disabled_days` listing the ones left out
+macro_rules! days {
+    ($($day:literal => $module:ident: $feature:literal),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            pub mod $module;
+        )*
+
+        /// Days that were left out of this build by disabling their feature
+        pub fn disabled_days() -> Vec<u8> {
+            [$(($day, cfg!(feature = $feature))),*]
+                .into_iter()
+                .filter(|(_, enabled)| !enabled)
+                .map(|(day, _)| day)
+                .collect()
+        }
+    };
+}
+
+/// Declares the module of each year, along with the functions that look into
+/// all of them
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Years with solutions, including ones with all days left out of this build
+        pub const YEARS: &[u16] = &[$($year),*];
+
+        /// Days of `year` that have a solution, but not in this build
+        pub fn disabled_days(year: u16) -> Vec<u8> {
+            match year {
+                $($year => $module::disabled_days(),)*
+                _ => vec![],
+            }
+        }
+    };
+}
+
+years! {
+    2023 => year_2023,
+}
+
+/// Name of the Cargo feature that builds `day` of `year`, e.g. `y2023-day-05`.
+/// The year is part of the name so that days of different years can be left
+/// out separately.
+pub fn feature(year: u16, day: u8) -> String {
+    format!("y{year}-day-{day:02}")
+}
+
+/// Year that commands default to when none is given
+pub const LATEST_YEAR: u16 = YEARS[YEARS.len() - 1];
+
+/// Common interface of every day. Each day module exposes a `Solver` that
+/// implements it and registers it with the `#[aoc]` attribute, so that the
+/// runner doesn't have to know anything about how a particular day works.
+pub trait Solution {
+    type Parsed;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Self::Part1;
+    fn part2(parsed: &Self::Parsed) -> Self::Part2;
+}
@@ -0,0 +1,27 @@
+days! {
+    1 => day_01: "y2023-day-01",
+    2 => day_02: "y2023-day-02",
+    3 => day_03: "y2023-day-03",
+    4 => day_04: "y2023-day-04",
+    5 => day_05: "y2023-day-05",
+    6 => day_06: "y2023-day-06",
+    7 => day_07: "y2023-day-07",
+    8 => day_08: "y2023-day-08",
+    9 => day_09: "y2023-day-09",
+    10 => day_10: "y2023-day-10",
+    11 => day_11: "y2023-day-11",
+    12 => day_12: "y2023-day-12",
+    13 => day_13: "y2023-day-13",
+    14 => day_14: "y2023-day-14",
+    15 => day_15: "y2023-day-15",
+    16 => day_16: "y2023-day-16",
+    17 => day_17: "y2023-day-17",
+    18 => day_18: "y2023-day-18",
+    19 => day_19: "y2023-day-19",
+    20 => day_20: "y2023-day-20",
+    21 => day_21: "y2023-day-21",
+    22 => day_22: "y2023-day-22",
+    23 => day_23: "y2023-day-23",
+    24 => day_24: "y2023-day-24",
+    25 => day_25: "y2023-day-25",
+}
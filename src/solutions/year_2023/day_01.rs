@@ -0,0 +1,205 @@
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{parse::ParseError, solutions::Solution};
+
+fn find_first_last_number(numbers: Vec<u32>) -> Option<(u32, u32)> {
+    Some((*numbers.first()?, *numbers.last()?))
+}
+
+fn digits_in_line(line: &str) -> Vec<u32> {
+    line.chars()
+        .filter(|c| c.is_ascii_digit())
+        .map(|c| c.to_digit(10).unwrap())
+        .collect()
+}
+
+/// Calibration values of the lines that have one, which in part 1 leaves out
+/// lines with only spelled out digits
+fn calibration_values(lines: &[String], tokenize_line: fn(&str) -> Vec<u32>) -> Vec<u32> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let tokenized = tokenize_line(line);
+            let (first, last) = find_first_last_number(tokenized)?;
+
+            Some(first * 10 + last)
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .lines()
+        .map(|line| match tokenize(line).is_empty() {
+            true => Err(ParseError::new(line, "a line with at least one digit")),
+            false => Ok(line.to_string()),
+        })
+        .collect()
+}
+
+fn matches_string(chars: &[char], start: usize, expected_string: &str) -> bool {
+    chars
+        .get(start..start + expected_string.len())
+        .map(|chars| chars.iter().collect::<String>())
+        .unwrap_or(String::from(""))
+        == *expected_string
+}
+
+fn tokenize(line: &str) -> Vec<u32> {
+    let lowercase_line = line.to_lowercase();
+    let chars = lowercase_line.chars().collect::<Vec<_>>();
+    let mut ptr = 0;
+    let mut result = vec![];
+    while let Some(char) = chars.get(ptr) {
+        match char {
+            x if x.is_ascii_digit() => {
+                result.push(x.to_digit(10).unwrap());
+            }
+            // Do not consume the ptr, because oneight counts as both 1 and 8
+            'o' if matches_string(&chars, ptr, "one") => {
+                result.push(1);
+            }
+            't' if matches_string(&chars, ptr, "two") => {
+                result.push(2);
+            }
+            't' if matches_string(&chars, ptr, "three") => {
+                result.push(3);
+            }
+            'f' if matches_string(&chars, ptr, "four") => {
+                result.push(4);
+            }
+            'f' if matches_string(&chars, ptr, "five") => {
+                result.push(5);
+            }
+            's' if matches_string(&chars, ptr, "six") => {
+                result.push(6);
+            }
+            's' if matches_string(&chars, ptr, "seven") => {
+                result.push(7);
+            }
+            'e' if matches_string(&chars, ptr, "eight") => {
+                result.push(8);
+            }
+            'n' if matches_string(&chars, ptr, "nine") => {
+                result.push(9);
+            }
+            _ => {}
+        }
+        ptr += 1
+    }
+    result
+}
+
+fn sum_calibration(lines: &[String], tokenize_line: fn(&str) -> Vec<u32>) -> u32 {
+    calibration_values(lines, tokenize_line).iter().sum()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 1)]
+impl Solution for Solver {
+    type Parsed = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Parsed) -> Self::Part1 {
+        sum_calibration(lines, digits_in_line)
+    }
+
+    fn part2(lines: &Self::Parsed) -> Self::Part2 {
+        sum_calibration(lines, tokenize)
+    }
+}
+
+#[aoc(year = 2023, day = 1, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut input = String::new();
+    for _ in 0..size {
+        // Part 1 needs at least one actual digit on every line
+        let digit_at = rng.gen_range(0..8);
+        for i in 0..8 {
+            match rng.gen_range(0..3) {
+                0 => input.push(rng.gen_range('a'..='z')),
+                1 => input += WORDS[rng.gen_range(0..WORDS.len())],
+                _ => input.push(rng.gen_range('1'..='9')),
+            }
+            if i == digit_at {
+                input.push(rng.gen_range('1'..='9'));
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[aoc(year = 2023, day = 1, part1 = 142)]
+const SAMPLE: &str = indoc! {"
+    1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet
+"};
+
+#[aoc(year = 2023, day = 1, part2 = 281)]
+const COMPLEX_SAMPLE: &str = indoc! {"
+    two1nine
+    eightwothree
+    abcone2threexyz
+    xtwone3four
+    4nineeightseven2
+    zoneight234
+    7pqrstsixteen
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_calibration_values() {
+        let lines = parse_input(SAMPLE).unwrap();
+        assert_eq!(
+            calibration_values(&lines, digits_in_line),
+            vec![12, 38, 15, 77]
+        );
+        assert_eq!(sum_calibration(&lines, digits_in_line), 142);
+    }
+
+    #[test]
+    fn test_complex_calibration_values() {
+        let lines = parse_input(COMPLEX_SAMPLE).unwrap();
+        assert_eq!(
+            calibration_values(&lines, tokenize),
+            vec![29, 83, 13, 24, 42, 14, 76]
+        );
+        assert_eq!(sum_calibration(&lines, tokenize), 281);
+        // Without its spelled out digits, `eightwothree` has no value
+        assert_eq!(
+            calibration_values(&lines, digits_in_line),
+            vec![11, 22, 33, 42, 24, 77]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(parse_input("abc\n").is_err());
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("two1nine"), vec![2, 1, 9]);
+        assert_eq!(
+            tokenize("1onetwothreefourfivesixseveneight8nine"),
+            vec![1, 1, 2, 3, 4, 5, 6, 7, 8, 8, 9]
+        );
+    }
+}
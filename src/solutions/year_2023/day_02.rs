@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+type Game = (u32, Vec<HashMap<String, u32>>);
+
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .map(|game| {
+            let (game_and_id, sets) = parse::split_once(game, ": ")?;
+            let id = parse::number(parse::strip_prefix(game_and_id, "Game ")?)?;
+            Ok((
+                id,
+                sets.split("; ")
+                    .map(|set| {
+                        set.split(", ")
+                            .map(|cube| {
+                                let (count, color) = parse::split_once(cube, " ")?;
+                                Ok((color.to_string(), parse::number(count)?))
+                            })
+                            .collect::<Result<HashMap<_, _>, _>>()
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            ))
+        })
+        .collect()
+}
+
+fn sum_possible_games(games: &[Game], possible_values: &HashMap<&str, u32>) -> u32 {
+    games
+        .iter()
+        .filter(|(_, game)| {
+            game.iter().all(|set| {
+                set.iter()
+                    .all(|(color, count)| matches!((possible_values.get(color.as_str()), count), (Some(possible), count) if possible >= count))
+            })
+        })
+        .map(|(id, _)| id)
+        .sum()
+}
+
+fn sum_cube_power(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|(_, game)| {
+            game.iter()
+                .fold(HashMap::new(), |mut acc, set| {
+                    set.iter().for_each(|(color, count)| {
+                        let entry = acc.entry(color.as_str()).or_insert(*count);
+                        *entry = (*entry).max(*count);
+                    });
+                    acc
+                })
+                .values()
+                .product::<u32>()
+        })
+        .sum()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 2)]
+impl Solution for Solver {
+    type Parsed = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_games(input)
+    }
+
+    fn part1(games: &Self::Parsed) -> Self::Part1 {
+        sum_possible_games(
+            games,
+            &HashMap::from([("blue", 14), ("red", 12), ("green", 13)]),
+        )
+    }
+
+    fn part2(games: &Self::Parsed) -> Self::Part2 {
+        sum_cube_power(games)
+    }
+}
+
+#[aoc(year = 2023, day = 2, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let sets = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = vec![];
+                for color in ["red", "green", "blue"] {
+                    if rng.gen_bool(0.7) {
+                        colors.push(format!("{} {color}", rng.gen_range(1..=20)));
+                    }
+                }
+                if colors.is_empty() {
+                    colors.push(format!("{} blue", rng.gen_range(1..=20)));
+                }
+                colors.join(", ")
+            })
+            .collect::<Vec<_>>();
+        input += &format!("Game {id}: {}\n", sets.join("; "));
+    }
+    input
+}
+
+#[aoc(year = 2023, day = 2, part1 = 8, part2 = 2286)]
+const SAMPLE: &str = indoc! {"
+    Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+    Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+    Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"};
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_sum_possible_games() {
+        assert_eq!(
+            sum_possible_games(
+                &parse_games(SAMPLE).unwrap(),
+                &HashMap::from([("blue", 14), ("red", 12), ("green", 13)])
+            ),
+            8
+        )
+    }
+
+    #[test]
+    fn test_sum_cube_power() {
+        assert_eq!(sum_cube_power(&parse_games(SAMPLE).unwrap()), 2286);
+    }
+}
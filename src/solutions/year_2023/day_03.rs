@@ -1,8 +1,10 @@
-const INPUT: &str = include_str!("day_03.txt");
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
 
-fn sum_adjacent(input: &str) -> u32 {
-    let matrix: Vec<Vec<char>> = input.lines().map(|x| x.chars().collect()).collect();
+use crate::{parse::ParseError, solutions::Solution, utils::ToMatrix};
 
+fn sum_adjacent(matrix: &[Vec<char>]) -> u32 {
     let rows = matrix.len();
     let cols = matrix.first().unwrap().len();
     let mut sum = 0;
@@ -10,9 +12,9 @@ fn sum_adjacent(input: &str) -> u32 {
         let mut col = 0;
         while col < cols {
             if matrix[row][col].is_ascii_digit() {
-                let row_above = if row > 1 { row - 1 } else { 0 };
+                let row_above = row.saturating_sub(1);
                 let row_below = (row + 1).min(rows - 1);
-                let col_left = if col > 1 { col - 1 } else { 0 };
+                let col_left = col.saturating_sub(1);
                 let mut col_right = (col + 1).min(cols - 1);
                 let mut nums = vec![matrix[row][col]];
                 while col_right < cols && matrix[row][col_right].is_ascii_digit() {
@@ -41,9 +43,7 @@ fn sum_adjacent(input: &str) -> u32 {
     sum
 }
 
-fn sum_gear_ratios(input: &str) -> u32 {
-    let matrix: Vec<Vec<char>> = input.lines().map(|x| x.chars().collect()).collect();
-
+fn sum_gear_ratios(matrix: &[Vec<char>]) -> u32 {
     let mut r = 0;
     let mut all_numbers = vec![];
     let mut stars = vec![];
@@ -108,36 +108,80 @@ fn sum_gear_ratios(input: &str) -> u32 {
         .sum()
 }
 
-pub fn print_solution() {
-    println!("Sum of adjacent numbers: {sum}", sum = sum_adjacent(INPUT));
-    println!("Sum of gear ratios: {sum}", sum = sum_gear_ratios(INPUT));
+pub struct Solver;
+
+#[aoc(year = 2023, day = 3)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<char>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.matrix())
+    }
+
+    fn part1(matrix: &Self::Parsed) -> Self::Part1 {
+        sum_adjacent(matrix)
+    }
+
+    fn part2(matrix: &Self::Parsed) -> Self::Part2 {
+        sum_gear_ratios(matrix)
+    }
 }
 
+#[aoc(year = 2023, day = 3, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@%&=-";
+    let size = size.max(3);
+    let mut grid = vec![vec![b'.'; size]; size];
+    for row in grid.iter_mut() {
+        let mut col = 0;
+        while col < size {
+            match rng.gen_range(0..10) {
+                0 => row[col] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())],
+                1 | 2 => {
+                    // A number is always followed by a gap or the end of the row
+                    let digits = rng.gen_range(1..=3).min(size - col);
+                    for cell in &mut row[col..col + digits] {
+                        *cell = rng.gen_range(b'1'..=b'9');
+                    }
+                    col += digits;
+                }
+                _ => (),
+            }
+            col += 1;
+        }
+    }
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+#[aoc(year = 2023, day = 3, part1 = 4361, part2 = 467835)]
+const SAMPLE: &str = indoc! {"
+    467..114.
+    ...*.....
+    ..35..633
+    ......#..
+    617*.....
+    .....+.58
+    ..592....
+    ......755
+    ...$.*...
+    .664.598.
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use indoc::indoc;
-
-    const SAMPLE: &str = indoc! {"
-        467..114.
-        ...*.....
-        ..35..633
-        ......#..
-        617*.....
-        .....+.58
-        ..592....
-        ......755
-        ...$.*...
-        .664.598.
-    "};
 
     #[test]
     fn test_sum_adjacent() {
-        assert_eq!(sum_adjacent(SAMPLE), 4361);
+        assert_eq!(sum_adjacent(&SAMPLE.matrix()), 4361);
     }
 
     #[test]
     fn test_sum_gear_ratios() {
-        assert_eq!(sum_gear_ratios(SAMPLE), 467835);
+        assert_eq!(sum_gear_ratios(&SAMPLE.matrix()), 467835);
     }
 }
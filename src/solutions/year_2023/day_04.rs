@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use aoc::aoc;
+use indoc::indoc;
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+fn count_winners(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let game = parse::split_once(line, ": ")?.1;
+            let (winners_str, numbers_str) = parse::split_once(game, " | ")?;
+            let winners: HashSet<u32> = parse::numbers(winners_str)?.into_iter().collect();
+            Ok(parse::numbers::<u32>(numbers_str)?
+                .into_iter()
+                .filter(|num| winners.contains(num))
+                .count() as u32)
+        })
+        .collect()
+}
+
+fn sum_of_winning_numbers(winning_numbers: &[u32]) -> u32 {
+    winning_numbers
+        .iter()
+        .map(|winning_count| {
+            if *winning_count == 0 {
+                0
+            } else {
+                2_u32.pow(winning_count - 1)
+            }
+        })
+        .sum()
+}
+
+fn determine_scratchcard_copies(winning_numbers: &[u32]) -> Vec<u32> {
+    winning_numbers.iter().enumerate().fold(
+        vec![1; winning_numbers.len()],
+        |mut copies, (idx, number_of_winners)| {
+            let indices_that_get_copies = idx + 1..=idx + *number_of_winners as usize;
+            indices_that_get_copies.for_each(|copy_idx| {
+                copies[copy_idx] += copies[idx];
+            });
+            copies
+        },
+    )
+}
+
+fn sum_of_scratchcard_copies(winning_numbers: &[u32]) -> u32 {
+    determine_scratchcard_copies(winning_numbers).iter().sum()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 4)]
+impl Solution for Solver {
+    type Parsed = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        count_winners(input)
+    }
+
+    fn part1(winning_numbers: &Self::Parsed) -> Self::Part1 {
+        sum_of_winning_numbers(winning_numbers)
+    }
+
+    fn part2(winning_numbers: &Self::Parsed) -> Self::Part2 {
+        sum_of_scratchcard_copies(winning_numbers)
+    }
+}
+
+#[aoc(year = 2023, day = 4, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Keep the total number of scratchcards within a `u32`
+    let mut copies = vec![1_u64; size];
+    let mut input = String::new();
+    for card in 0..size {
+        let mut matches = match rng.gen_range(0..3) {
+            0 => rng.gen_range(0..=10),
+            _ => 0,
+        }
+        .min(size - card - 1);
+        let total = copies.iter().sum::<u64>() + copies[card] * matches as u64;
+        if total > u64::from(u32::MAX / 2) {
+            matches = 0;
+        }
+        for copy in card + 1..=card + matches {
+            copies[copy] += copies[card];
+        }
+
+        let mut pool = (1..100).collect::<Vec<u32>>();
+        pool.shuffle(rng);
+        let (winners, others) = pool.split_at(10);
+        let mut numbers = winners[..matches]
+            .iter()
+            .chain(&others[..25 - matches])
+            .collect::<Vec<_>>();
+        numbers.shuffle(rng);
+        let format = |numbers: &[&u32]| numbers.iter().map(|n| format!("{n:>2}")).join(" ");
+        input += &format!(
+            "Card {:>3}: {} | {}\n",
+            card + 1,
+            format(&winners.iter().collect::<Vec<_>>()),
+            format(&numbers)
+        );
+    }
+    input
+}
+
+#[aoc(year = 2023, day = 4, part1 = 13, part2 = 30)]
+const SAMPLE: &str = indoc! {"
+    Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sum_winning_numbers() {
+        assert_eq!(sum_of_winning_numbers(&count_winners(SAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_determine_scratchcard_copies() {
+        assert_eq!(
+            determine_scratchcard_copies(&count_winners(SAMPLE).unwrap()),
+            vec![1, 2, 4, 8, 14, 1]
+        );
+    }
+
+    #[test]
+    fn test_sum_of_scratchcard_copies() {
+        assert_eq!(
+            sum_of_scratchcard_copies(&count_winners(SAMPLE).unwrap()),
+            30
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use std::ops::Range;
+
+use aoc::aoc;
+use indoc::indoc;
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+type Almanac = (Vec<usize>, Vec<Vec<(Range<usize>, Range<usize>)>>);
+
+fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    let mut iter = input.lines();
+    let seeds = parse::numbers(parse::strip_prefix(
+        iter.next().unwrap_or(input),
+        "seeds: ",
+    )?)?;
+
+    // Empty line
+    iter.next();
+    // first line is the kind
+    iter.next();
+
+    let mut result_ranges = vec![];
+    loop {
+        let mut ranges = vec![];
+        loop {
+            let line = iter.next().unwrap_or("");
+            if line.trim().is_empty() {
+                break;
+            }
+            let [dest_start, src_start, len] = parse::numbers(line)?[..] else {
+                return Err(ParseError::new(line, "three numbers"));
+            };
+            ranges.push((dest_start..dest_start + len, src_start..src_start + len));
+        }
+        result_ranges.push(ranges);
+
+        if iter.next().is_none() {
+            break;
+        }
+    }
+
+    Ok((seeds, result_ranges))
+}
+
+fn location_number_for_seed(seed: usize, overrides: &[Vec<(Range<usize>, Range<usize>)>]) -> usize {
+    overrides.iter().fold(seed, |num, from_to| {
+        from_to
+            .iter()
+            .find(|elem| elem.1.contains(&num))
+            .map(|(dest_range, src_range)| {
+                let offset = num - src_range.start;
+                dest_range.start + offset
+            })
+            .unwrap_or(num)
+    })
+}
+
+fn lowest_number_multi(almanac: &Almanac) -> usize {
+    let seeds: Vec<_> = almanac
+        .0
+        .chunks(2)
+        .flat_map(|seed_range| {
+            if let [start, len] = *seed_range {
+                (start..start + len).collect()
+            } else {
+                vec![]
+            }
+        })
+        .collect();
+
+    seeds
+        .iter()
+        .map(|seed| location_number_for_seed(*seed, &almanac.1))
+        .min()
+        .unwrap()
+}
+
+fn lowest_number_single(almanac: &Almanac) -> usize {
+    almanac
+        .0
+        .iter()
+        .map(|seed| location_number_for_seed(*seed, &almanac.1))
+        .min()
+        .unwrap()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 5)]
+impl Solution for Solver {
+    type Parsed = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Self::Part1 {
+        lowest_number_single(almanac)
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Self::Part2 {
+        lowest_number_multi(almanac)
+    }
+}
+
+#[aoc(year = 2023, day = 5, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    let limit = size.max(1) * 1000;
+    // Part 2 tries every seed, so the ranges have to stay small
+    let seeds = (0..10)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.gen_range(0..limit),
+                rng.gen_range(1..=size.max(1) * 10)
+            )
+        })
+        .join(" ");
+    let mut input = format!("seeds: {seeds}\n");
+    for name in MAPS {
+        input += &format!("\n{name} map:\n");
+        // Split part of the numbers into ranges and lay them out in a different order
+        let mut bounds = (0..rng.gen_range(2..=8))
+            .map(|_| rng.gen_range(0..limit))
+            .collect::<Vec<_>>();
+        bounds.sort();
+        bounds.dedup();
+        let mut sources = bounds
+            .windows(2)
+            .map(|w| (w[0], w[1] - w[0]))
+            .collect::<Vec<_>>();
+        sources.shuffle(rng);
+        let mut destination = bounds[0];
+        for (source, len) in sources {
+            input += &format!("{destination} {source} {len}\n");
+            destination += len;
+        }
+    }
+    input
+}
+
+#[aoc(year = 2023, day = 5, part1 = 35, part2 = 46)]
+const SAMPLE: &str = indoc! {"
+    seeds: 79 14 55 13
+
+    seed-to-soil map:
+    50 98 2
+    52 50 48
+
+    soil-to-fertilizer map:
+    0 15 37
+    37 52 2
+    39 0 15
+
+    fertilizer-to-water map:
+    49 53 8
+    0 11 42
+    42 0 7
+    57 7 4
+
+    water-to-light map:
+    88 18 7
+    18 25 70
+
+    light-to-temperature map:
+    45 77 23
+    81 45 19
+    68 64 13
+
+    temperature-to-humidity map:
+    0 69 1
+    1 0 69
+
+    humidity-to-location map:
+    60 56 37
+    56 93 4
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_input() {
+        let parsed = parse_input(SAMPLE).unwrap();
+        assert_eq!(parsed.0, vec![79, 14, 55, 13]);
+        assert_eq!(
+            parsed.1,
+            vec![
+                vec![(50..52, 98..100), (52..52 + 48, 50..50 + 48)],
+                vec![(0..37, 15..15 + 37), (37..39, 52..54), (39..39 + 15, 0..15)],
+                vec![
+                    (49..57, 53..61,),
+                    (0..42, 11..53,),
+                    (42..49, 0..7,),
+                    (57..61, 7..11,),
+                ],
+                vec![(88..95, 18..25,), (18..88, 25..95,),],
+                vec![(45..68, 77..100,), (81..100, 45..64,), (68..81, 64..77,),],
+                vec![(0..1, 69..70,), (1..70, 0..69,),],
+                vec![(60..97, 56..93,), (56..60, 93..97,),],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lowest_number_single() {
+        let almanac = parse_input(SAMPLE).unwrap();
+        assert_eq!(lowest_number_single(&almanac), 35)
+    }
+
+    #[test]
+    fn test_lowest_number_multi() {
+        let almanac = parse_input(SAMPLE).unwrap();
+        assert_eq!(lowest_number_multi(&almanac), 46)
+    }
+}
@@ -0,0 +1,191 @@
+use aoc::aoc;
+use indoc::indoc;
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+type TimesTable = Vec<(usize, usize)>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Races {
+    table: TimesTable,
+    /// The one race that the table really describes, with really bad kerning
+    kerned: (usize, usize),
+}
+
+fn kern(line: &str, numbers: &[usize]) -> Result<usize, ParseError> {
+    if numbers.is_empty() {
+        return Err(ParseError::new(line, "at least one number"));
+    }
+    numbers
+        .iter()
+        .join("")
+        .parse()
+        .map_err(|_| ParseError::new(line, "numbers that still fit in one when joined"))
+}
+
+fn parse_input(input: &str) -> Result<Races, ParseError> {
+    let mut lines = input.lines();
+    let (Some(times), Some(distances)) = (lines.next(), lines.next()) else {
+        return Err(ParseError::new(
+            input,
+            "a line of times and one of distances",
+        ));
+    };
+    let time_numbers = parse::numbers(times.strip_prefix("Time: ").unwrap_or(times))?;
+    let distance_numbers =
+        parse::numbers(distances.strip_prefix("Distance: ").unwrap_or(distances))?;
+    let kerned = (
+        kern(times, &time_numbers)?,
+        kern(distances, &distance_numbers)?,
+    );
+    let table = time_numbers.into_iter().zip(distance_numbers).collect();
+    Ok(Races { table, kerned })
+}
+
+fn count_ways_to_win(race_time: usize, distance_to_beat: usize) -> usize {
+    (1..race_time)
+        .map(|hold_time| {
+            let remaining_time = race_time - hold_time;
+            let race_distance = remaining_time * hold_time;
+            if race_distance > distance_to_beat {
+                1
+            } else {
+                0
+            }
+        })
+        .sum::<usize>()
+}
+
+/// Same as `count_ways_to_win`, but makes use of the distance being symmetric
+/// around half the race time, only searching for the shortest winning hold time
+fn count_ways_to_win_bisect(race_time: usize, distance_to_beat: usize) -> usize {
+    let wins = |hold_time: usize| (race_time - hold_time) * hold_time > distance_to_beat;
+    let (mut lower, mut upper) = (0, race_time / 2);
+    if !wins(upper) {
+        return 0;
+    }
+    while upper - lower > 1 {
+        let middle = (lower + upper) / 2;
+        if wins(middle) {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+    race_time - 2 * upper + 1
+}
+
+fn product_of_ways_to_win(times_table: &TimesTable) -> usize {
+    times_table
+        .iter()
+        .map(|(time, distance)| count_ways_to_win(*time, *distance))
+        .product()
+}
+
+fn really_bad_kerning(races: &Races, count_ways: fn(usize, usize) -> usize) -> usize {
+    let (time, distance) = races.kerned;
+    count_ways(time, distance)
+}
+
+#[aoc(year = 2023, day = 6, part = 2)]
+fn bisect(races: &Races) -> usize {
+    really_bad_kerning(races, count_ways_to_win_bisect)
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 6)]
+impl Solution for Solver {
+    type Parsed = Races;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(races: &Self::Parsed) -> Self::Part1 {
+        product_of_ways_to_win(&races.table)
+    }
+
+    fn part2(races: &Self::Parsed) -> Self::Part2 {
+        really_bad_kerning(races, count_ways_to_win)
+    }
+}
+
+#[aoc(year = 2023, day = 6, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Part 2 tries every hold time of the joined race, so keep the times short
+    let max_time = size.clamp(7, 99);
+    let races = (0..4)
+        .map(|_| {
+            let time = rng.gen_range(7..=max_time);
+            let record = (time / 2) * (time - time / 2);
+            (time, rng.gen_range(record / 2..record))
+        })
+        .collect::<Vec<_>>();
+    let row = |numbers: Vec<usize>| numbers.iter().map(|n| format!("{n:>6}")).join("");
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        row(races.iter().map(|race| race.0).collect()),
+        row(races.iter().map(|race| race.1).collect())
+    )
+}
+
+#[aoc(year = 2023, day = 6, part1 = 288, part2 = 71503)]
+const SAMPLE: &str = indoc! {"
+    Time:      7  15   30
+    Distance:  9  40  200
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_input(SAMPLE).unwrap(),
+            Races {
+                table: vec![(7, 9), (15, 40), (30, 200)],
+                kerned: (71530, 940200),
+            }
+        );
+        assert!(parse_input("Time:\nDistance:\n").is_err());
+        assert!(parse_input("Time: 7 15 30\nDistance: 12345678901 12345678901\n").is_err());
+    }
+
+    #[test]
+    fn test_product_of_ways_to_win() {
+        assert_eq!(
+            product_of_ways_to_win(&parse_input(SAMPLE).unwrap().table),
+            288
+        );
+    }
+
+    #[test]
+    fn test_really_bad_kerning() {
+        assert_eq!(
+            really_bad_kerning(&parse_input(SAMPLE).unwrap(), count_ways_to_win),
+            71503
+        )
+    }
+
+    #[test]
+    fn test_count_ways_to_win_bisect() {
+        for (time, distance) in parse_input(SAMPLE).unwrap().table {
+            assert_eq!(
+                count_ways_to_win_bisect(time, distance),
+                count_ways_to_win(time, distance)
+            );
+        }
+        assert_eq!(count_ways_to_win_bisect(4, 10), 0);
+        assert_eq!(bisect(&parse_input(SAMPLE).unwrap()), 71503)
+    }
+}
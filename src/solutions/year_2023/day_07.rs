@@ -1,6 +1,14 @@
 use std::{collections::HashMap, str::FromStr};
 
-const INPUT: &str = include_str!("day_07.txt");
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
 const CARD_VALUES: [char; 13] = [
     'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
 ];
@@ -16,8 +24,8 @@ enum HandType {
     HighCard,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Hand {
+#[derive(Debug, Clone)]
+pub struct Hand {
     cards: Vec<char>,
     hand_type: HandType,
     bid: usize,
@@ -30,9 +38,6 @@ impl Hand {
     }
 }
 
-#[derive(Debug)]
-struct HandParseError;
-
 fn determine_type_with_joker(existing_type: HandType, joker_count: usize) -> HandType {
     match (existing_type, joker_count) {
         (HandType::FiveOfAKind, _) => HandType::FiveOfAKind,
@@ -80,35 +85,32 @@ fn determine_type(card_counts: HashMap<char, u32>) -> HandType {
 }
 
 impl FromStr for Hand {
-    type Err = HandParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split_once(' ');
-        match parts {
-            Some((cards, bid)) => {
-                let bid_num = bid.parse();
-                let card_counts = cards.chars().fold(HashMap::new(), |mut map, key| {
-                    let entry = map.entry(key).or_insert(0);
-                    *entry += 1;
-                    map
-                });
-                match bid_num {
-                    Ok(num) => Ok(Hand {
-                        cards: cards.chars().collect(),
-                        hand_type: determine_type(card_counts),
-                        bid: num,
-                        to_value_fn: to_value,
-                    }),
-                    Err(_) => Err(HandParseError),
-                }
-            }
-            None => Err(HandParseError),
+        let (cards, bid) = parse::split_once(s, " ")?;
+        if let Some((idx, c)) = cards.char_indices().find(|(_, c)| !CARD_VALUES.contains(c)) {
+            return Err(ParseError::new(&cards[idx..idx + c.len_utf8()], "a card"));
         }
+        if cards.len() != 5 {
+            return Err(ParseError::new(cards, "five cards"));
+        }
+        let card_counts = cards.chars().fold(HashMap::new(), |mut map, key| {
+            let entry = map.entry(key).or_insert(0);
+            *entry += 1;
+            map
+        });
+        Ok(Hand {
+            cards: cards.chars().collect(),
+            hand_type: determine_type(card_counts),
+            bid: parse::number(bid)?,
+            to_value_fn: to_value,
+        })
     }
 }
 
-fn parse_input(input: &str) -> Vec<Hand> {
-    input.lines().map(|h| h.parse().unwrap()).collect()
+fn parse_input(input: &str) -> Result<Vec<Hand>, ParseError> {
+    input.lines().map(str::parse).collect()
 }
 
 fn to_value(key: char) -> usize {
@@ -122,6 +124,14 @@ fn to_value_joker(key: char) -> usize {
     to_value(key)
 }
 
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards == other.cards && self.hand_type == other.hand_type && self.bid == other.bid
+    }
+}
+
+impl Eq for Hand {}
+
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -142,7 +152,8 @@ impl Ord for Hand {
     }
 }
 
-fn determine_winnings(hands: &mut [Hand]) -> usize {
+fn determine_winnings(hands: &[Hand]) -> usize {
+    let mut hands = hands.to_vec();
     hands.sort();
     hands
         .iter()
@@ -174,39 +185,75 @@ fn determine_winnings_joker(hands: &[Hand]) -> usize {
         .sum()
 }
 
-pub fn print_solution() {
-    let mut hands = parse_input(INPUT);
-    println!(
-        "Camel poker winnings: {winnings}",
-        winnings = determine_winnings(&mut hands)
-    );
-    println!(
-        "Camel poker winnings with joker: {winnings}",
-        winnings = determine_winnings_joker(&hands)
-    );
+pub struct Solver;
+
+#[aoc(year = 2023, day = 7)]
+impl Solution for Solver {
+    type Parsed = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(hands: &Self::Parsed) -> Self::Part1 {
+        determine_winnings(hands)
+    }
+
+    fn part2(hands: &Self::Parsed) -> Self::Part2 {
+        determine_winnings_joker(hands)
+    }
+}
+
+#[aoc(year = 2023, day = 7, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let cards = (0..5)
+                .map(|_| match rng.gen_range(0..3) {
+                    // Favour a few cards, to get more pairs and full houses
+                    0 => CARD_VALUES[rng.gen_range(0..3)],
+                    _ => CARD_VALUES[rng.gen_range(0..CARD_VALUES.len())],
+                })
+                .collect::<String>();
+            format!("{cards} {}\n", rng.gen_range(1..=1000))
+        })
+        .collect()
 }
 
+#[aoc(year = 2023, day = 7, part1 = 6440, part2 = 5905)]
+const SAMPLE: &str = indoc! {"
+    32T3K 765
+    T55J5 684
+    KK677 28
+    KTJJT 220
+    QQQJA 483
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        32T3K 765
-        T55J5 684
-        KK677 28
-        KTJJT 220
-        QQQJA 483
-    "};
-
     #[test]
     fn test_determine_winnings() {
-        assert_eq!(determine_winnings(&mut parse_input(SAMPLE)), 6440);
+        assert_eq!(determine_winnings(&parse_input(SAMPLE).unwrap()), 6440);
     }
 
     #[test]
     fn test_determine_winnings_joker() {
-        assert_eq!(determine_winnings_joker(&parse_input(SAMPLE)), 5905);
+        assert_eq!(
+            determine_winnings_joker(&parse_input(SAMPLE).unwrap()),
+            5905
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let err = "AKé23 5".parse::<Hand>().unwrap_err();
+        assert_eq!(err.to_string(), "expected a card, found 'é'");
+        assert!("AK23 5".parse::<Hand>().is_err());
+        assert!("AKQ23".parse::<Hand>().is_err());
     }
 }
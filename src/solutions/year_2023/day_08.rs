@@ -0,0 +1,207 @@
+use aoc::aoc;
+use indoc::indoc;
+use num::Integer;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+struct MapInstruction {
+    left: String,
+    right: String,
+}
+
+pub struct Map {
+    instructions: Vec<char>,
+    path: HashMap<String, MapInstruction>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let instructions_str = lines.next().unwrap_or(s);
+        if let Some(idx) = instructions_str.find(|c| c != 'L' && c != 'R') {
+            return Err(ParseError::new(&instructions_str[idx..], "'L' or 'R'"));
+        }
+        let instructions = instructions_str.chars().collect();
+
+        // empty line
+        lines.next();
+
+        let path = lines.try_fold(HashMap::new(), |mut map, line| {
+            let (from, branches) = parse::split_once(line, " = ")?;
+
+            let (left, right) = parse::split_once(branches, ", ")?;
+            map.insert(
+                from.to_string(),
+                MapInstruction {
+                    left: parse::strip_prefix(left, "(")?.to_string(),
+                    right: parse::strip_suffix(right, ")")?.to_string(),
+                },
+            );
+
+            Ok(map)
+        })?;
+        Ok(Map { instructions, path })
+    }
+}
+
+fn count_walks(map: &Map, start: &String, end_condition: fn(&String) -> bool) -> usize {
+    let mut current = start;
+    map.instructions
+        .iter()
+        .cycle()
+        .take_while(|ins| {
+            let way = map.path.get(current).unwrap();
+            current = match **ins {
+                'L' => &way.left,
+                'R' => &way.right,
+                _ => panic!(),
+            };
+            end_condition(current)
+        })
+        .count()
+        + 1 // Need + 1 because take_while doesn't yield the last element
+}
+
+fn count_ghost_walks(map: &Map) -> usize {
+    let starting_nodes = map.path.keys().filter(|key| key.ends_with('A'));
+    let steps_to_z = starting_nodes.map(|node| count_walks(map, node, |str| !str.ends_with('Z')));
+    steps_to_z.reduce(|lcm, num| lcm.lcm(&num)).unwrap()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 8)]
+impl Solution for Solver {
+    type Parsed = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Part1 {
+        count_walks(map, &"AAA".to_string(), |str| str != "ZZZ")
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Part2 {
+        count_ghost_walks(map)
+    }
+}
+
+#[aoc(year = 2023, day = 8, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let length = size.clamp(1, 500);
+    let instructions = (0..length)
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect::<String>();
+    // Names that don't end in `A` or `Z`, for the nodes between start and end
+    let letter = |n: usize| (b'A' + n as u8) as char;
+    let mut names = (0..26 * 26 * 24)
+        .map(|n| {
+            format!(
+                "{}{}{}",
+                letter(n / 24 / 26),
+                letter(n / 24 % 26),
+                letter(1 + n % 24)
+            )
+        })
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+
+    // Like in the real inputs, each ghost reaches its end after a multiple of
+    // the instructions, and then loops back to the node after its start
+    let mut nodes = vec![];
+    for (ghost, period) in [3, 5, 7, 11].into_iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (
+                format!("{0}{0}A", letter(ghost)),
+                format!("{0}{0}Z", letter(ghost)),
+            ),
+        };
+        let steps = length * period;
+        let mut path = vec![start];
+        path.extend(names.drain(..steps - 1));
+        path.push(end);
+        let next = |step: usize| path[step % steps + 1].clone();
+        for (step, node) in path.iter().enumerate() {
+            let taken = next(step);
+            let decoy = path[rng.gen_range(0..path.len())].clone();
+            let (left, right) = match instructions.as_bytes()[step % length] {
+                b'L' => (taken, decoy),
+                _ => (decoy, taken),
+            };
+            nodes.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+    nodes.shuffle(rng);
+    format!("{instructions}\n\n{}\n", nodes.join("\n"))
+}
+
+#[aoc(year = 2023, day = 8, part1 = 2)]
+const SAMPLE_1: &str = indoc! {"
+    RL
+
+    AAA = (BBB, CCC)
+    BBB = (DDD, EEE)
+    CCC = (ZZZ, GGG)
+    DDD = (DDD, DDD)
+    EEE = (EEE, EEE)
+    GGG = (GGG, GGG)
+    ZZZ = (ZZZ, ZZZ)
+"};
+
+#[aoc(year = 2023, day = 8, part2 = 6)]
+const SAMPLE_GHOSTS: &str = indoc! {"
+    LR
+
+    11A = (11B, XXX)
+    11B = (XXX, 11Z)
+    11Z = (11B, XXX)
+    22A = (22B, XXX)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)
+    22Z = (22B, 22B)
+    XXX = (XXX, XXX)
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    const SAMPLE_2: &str = indoc! {"
+        LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)
+    "};
+
+    #[test]
+    fn test_count_walks() {
+        let start = "AAA".to_string();
+        assert_eq!(
+            count_walks(&SAMPLE_1.parse().unwrap(), &start, |str| str != "ZZZ"),
+            2
+        );
+        assert_eq!(
+            count_walks(&SAMPLE_2.parse().unwrap(), &start, |str| str != "ZZZ"),
+            6
+        );
+    }
+
+    #[test]
+    fn test_count_ghost_walks() {
+        assert_eq!(count_ghost_walks(&SAMPLE_GHOSTS.parse().unwrap()), 6);
+    }
+}
@@ -1,12 +1,17 @@
 use std::collections::VecDeque;
 
-const INPUT: &str = include_str!("day_09.txt");
+use aoc::aoc;
+use indoc::indoc;
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
-fn parse_input(input: &str) -> Vec<Vec<i64>> {
-    input
-        .lines()
-        .map(|l| l.split_whitespace().map(|n| n.parse().unwrap()).collect())
-        .collect()
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input.lines().map(parse::numbers).collect()
 }
 
 fn extrapolate(numbers: &[i64]) -> Vec<VecDeque<i64>> {
@@ -64,30 +69,61 @@ fn sum_all_extrapolations(numbers: &[Vec<i64>], fun: fn(&[VecDeque<i64>]) -> i64
     numbers.iter().map(|line| fun(&extrapolate(line))).sum()
 }
 
-pub fn print_solution() {
-    let input = parse_input(INPUT);
-    println!(
-        "Sum of ending extrapolations: {}",
-        sum_all_extrapolations(&input, last_of_first_elem)
-    );
-    println!(
-        "Sum of beginning extrapolations: {}",
-        sum_all_extrapolations(&input, first_of_first_elem)
-    );
+pub struct Solver;
+
+#[aoc(year = 2023, day = 9)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Parsed) -> Self::Part1 {
+        sum_all_extrapolations(numbers, last_of_first_elem)
+    }
+
+    fn part2(numbers: &Self::Parsed) -> Self::Part2 {
+        sum_all_extrapolations(numbers, first_of_first_elem)
+    }
+}
+
+#[aoc(year = 2023, day = 9, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            // Build the sequence from a constant row of differences upwards
+            let degree = rng.gen_range(0..=5);
+            let mut sequence = vec![rng.gen_range(-5..=5_i64); 21];
+            for _ in 0..degree {
+                sequence = sequence
+                    .iter()
+                    .scan(rng.gen_range(-20..=20), |value, diff| {
+                        let current = *value;
+                        *value += diff;
+                        Some(current)
+                    })
+                    .collect();
+            }
+            sequence.iter().join(" ") + "\n"
+        })
+        .collect()
 }
 
+#[aoc(year = 2023, day = 9, part1 = 114, part2 = 2)]
+const SAMPLE: &str = indoc! {"
+    0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        0 3 6 9 12 15
-        1 3 6 10 15 21
-        10 13 16 21 30 45
-    "};
-
     #[test]
     fn test_extrapolate() {
         // Part 1
@@ -109,11 +145,11 @@ mod test {
     #[test]
     fn test_summing() {
         assert_eq!(
-            sum_all_extrapolations(&parse_input(SAMPLE), last_of_first_elem),
+            sum_all_extrapolations(&parse_input(SAMPLE).unwrap(), last_of_first_elem),
             114
         );
         assert_eq!(
-            sum_all_extrapolations(&parse_input(SAMPLE), first_of_first_elem),
+            sum_all_extrapolations(&parse_input(SAMPLE).unwrap(), first_of_first_elem),
             2
         );
     }
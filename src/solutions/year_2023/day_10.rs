@@ -0,0 +1,413 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+const NORTH: (i64, i64) = (-1, 0);
+const SOUTH: (i64, i64) = (1, 0);
+const EAST: (i64, i64) = (0, 1);
+const WEST: (i64, i64) = (0, -1);
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PipePiece {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl FromStr for PipePiece {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "|" => Ok(Self::NorthSouth),
+            "-" => Ok(Self::EastWest),
+            "L" => Ok(Self::NorthEast),
+            "J" => Ok(Self::NorthWest),
+            "7" => Ok(Self::SouthWest),
+            "F" => Ok(Self::SouthEast),
+            "." => Ok(Self::Ground),
+            "S" => Ok(Self::Start),
+            _ => Err(ParseError::new(s, "a pipe piece, '.' or 'S'")),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<PipePiece>>, ParseError> {
+    if !input.contains('S') {
+        return Err(ParseError::new(&input[input.len()..], "a start tile 'S'"));
+    }
+    parse::matrix(input, str::parse)
+}
+
+fn find_loop(matrix: &[Vec<PipePiece>]) -> (PipePiece, Vec<(i64, i64)>) {
+    let start_pos = matrix
+        .iter()
+        .enumerate()
+        .find_map(|(row_idx, row)| {
+            row.iter().enumerate().find_map(|(col_idx, piece)| {
+                if *piece == PipePiece::Start {
+                    Some((row_idx as i64, col_idx as i64))
+                } else {
+                    None
+                }
+            })
+        })
+        .unwrap();
+
+    [NORTH, SOUTH, EAST, WEST]
+        .map(|heading| follow_direction(matrix, start_pos, heading))
+        .iter()
+        .max_by_key(|path| path.1.len())
+        .unwrap()
+        .clone()
+}
+
+fn find_loop_length(matrix: &[Vec<PipePiece>]) -> usize {
+    find_loop(matrix).1.len() / 2
+}
+
+fn count_insides(matrix: &[Vec<PipePiece>]) -> usize {
+    let path = find_loop(matrix);
+    let loop_path: HashSet<(i64, i64)> = HashSet::from_iter(path.1);
+    matrix
+        .iter()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            let mut is_inside = false;
+            row.iter()
+                .enumerate()
+                .filter(|(col_idx, piece)| {
+                    let current = (row_idx as i64, *col_idx as i64);
+                    let check_piece = if **piece == PipePiece::Start {
+                        &path.0
+                    } else {
+                        *piece
+                    };
+                    if loop_path.contains(&current) {
+                        if [
+                            PipePiece::NorthSouth,
+                            PipePiece::NorthWest,
+                            PipePiece::NorthEast,
+                        ]
+                        .contains(check_piece)
+                        {
+                            is_inside = !is_inside;
+                        }
+                        return false;
+                    }
+
+                    is_inside
+                })
+                .count()
+        })
+        .sum()
+}
+
+fn get_pos<T>(matrix: &[Vec<T>], pos: (i64, i64)) -> Option<&T> {
+    if pos.0 < 0 || pos.1 < 0 {
+        return None;
+    }
+    match matrix.get(pos.0 as usize) {
+        Some(row) => row.get(pos.1 as usize),
+        None => None,
+    }
+}
+
+fn follow_direction(
+    matrix: &[Vec<PipePiece>],
+    start: (i64, i64),
+    direction: (i64, i64),
+) -> (PipePiece, Vec<(i64, i64)>) {
+    let mut current_dir = direction;
+    let mut path = vec![start];
+    let mut last_heading = direction;
+    loop {
+        let mut current_pos = *path.last().unwrap();
+        current_pos.0 += current_dir.0;
+        current_pos.1 += current_dir.1;
+        match (get_pos(matrix, current_pos), current_dir) {
+            (Some(&PipePiece::NorthSouth), NORTH) => current_dir = NORTH,
+            (Some(&PipePiece::NorthSouth), SOUTH) => current_dir = SOUTH,
+            (Some(&PipePiece::EastWest), EAST) => current_dir = EAST,
+            (Some(&PipePiece::EastWest), WEST) => current_dir = WEST,
+            (Some(&PipePiece::NorthEast), SOUTH) => current_dir = EAST,
+            (Some(&PipePiece::NorthEast), WEST) => current_dir = NORTH,
+            (Some(&PipePiece::NorthWest), SOUTH) => current_dir = WEST,
+            (Some(&PipePiece::NorthWest), EAST) => current_dir = NORTH,
+            (Some(&PipePiece::SouthWest), NORTH) => current_dir = WEST,
+            (Some(&PipePiece::SouthWest), EAST) => current_dir = SOUTH,
+            (Some(&PipePiece::SouthEast), NORTH) => current_dir = EAST,
+            (Some(&PipePiece::SouthEast), WEST) => current_dir = SOUTH,
+            // We found the start!
+            (Some(&PipePiece::Start), _) => break,
+            // We followed the pipe and ended up in an impossible location
+            (Some(&PipePiece::Ground), _) => break,
+            (None, _) => break,
+            _ => break,
+        }
+        last_heading = current_dir;
+        path.push(current_pos);
+    }
+
+    let start_piece = match (direction, last_heading) {
+        (NORTH, SOUTH) => PipePiece::NorthSouth,
+        (NORTH, WEST) => PipePiece::SouthEast,
+        (WEST, NORTH) => PipePiece::SouthEast,
+        (NORTH, EAST) => PipePiece::SouthWest,
+        (EAST, NORTH) => PipePiece::SouthWest,
+        (EAST, WEST) => PipePiece::EastWest,
+        (WEST, EAST) => PipePiece::EastWest,
+        (SOUTH, EAST) => PipePiece::NorthWest,
+        (EAST, SOUTH) => PipePiece::NorthWest,
+        (WEST, SOUTH) => PipePiece::NorthEast,
+        (SOUTH, NORTH) => PipePiece::NorthSouth,
+        _ => PipePiece::Start,
+    };
+    (start_piece, path)
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 10)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<PipePiece>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(matrix: &Self::Parsed) -> Self::Part1 {
+        find_loop_length(matrix)
+    }
+
+    fn part2(matrix: &Self::Parsed) -> Self::Part2 {
+        count_insides(matrix)
+    }
+}
+
+#[aoc(year = 2023, day = 10, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // The loop is the outline of a random polyomino on a grid of `cells`²,
+    // drawn at twice the resolution so that the corners become pipe bends
+    let cells = (size.max(3) - 1) / 2;
+    let width = 2 * cells + 1;
+    let mut region = vec![vec![false; cells]; cells];
+    region[cells / 2][cells / 2] = true;
+    let inside = |region: &Vec<Vec<bool>>, row: i64, col: i64| {
+        (0..cells as i64).contains(&row)
+            && (0..cells as i64).contains(&col)
+            && region[row as usize][col as usize]
+    };
+    const RING: [(i64, i64); 8] = [
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+    ];
+    let mut members = vec![(cells / 2, cells / 2)];
+    for _ in 0..cells * cells * 4 {
+        let (row, col) = members[rng.gen_range(0..members.len())];
+        let (dr, dc) = RING[2 * rng.gen_range(0..4)];
+        let (row, col) = (row as i64 + dr, col as i64 + dc);
+        if !(0..cells as i64).contains(&row)
+            || !(0..cells as i64).contains(&col)
+            || inside(&region, row, col)
+        {
+            continue;
+        }
+        let ring = RING.map(|(dr, dc)| inside(&region, row + dr, col + dc));
+        // Adding the cell must neither touch the region only diagonally, which
+        // pinches the outline, nor join two parts of it, which encloses a hole
+        let pinched = (0..4).any(|i| ring[2 * i + 1] && !ring[2 * i] && !ring[(2 * i + 2) % 8]);
+        let arcs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+        if !pinched && arcs == 1 {
+            region[row as usize][col as usize] = true;
+            members.push((row as usize, col as usize));
+            if members.len() >= cells * cells / 2 {
+                break;
+            }
+        }
+    }
+
+    // Connect the tiles along the outline, corner (r, c) being tile (2r, 2c)
+    let mut openings = vec![vec![(false, false, false, false); width]; width];
+    let mut connect = |(r1, c1): (usize, usize), (r2, c2): (usize, usize)| {
+        let (r, c) = (r1 + r2, c1 + c2);
+        if r1 == r2 {
+            openings[2 * r1][2 * c1.min(c2)].2 = true;
+            openings[r][c].2 = true;
+            openings[r][c].3 = true;
+            openings[2 * r1][2 * c1.max(c2)].3 = true;
+        } else {
+            openings[2 * r1.min(r2)][2 * c1].1 = true;
+            openings[r][c].0 = true;
+            openings[r][c].1 = true;
+            openings[2 * r1.max(r2)][2 * c1].0 = true;
+        }
+    };
+    for &(row, col) in &members {
+        let (r, c) = (row as i64, col as i64);
+        if !inside(&region, r - 1, c) {
+            connect((row, col), (row, col + 1));
+        }
+        if !inside(&region, r + 1, c) {
+            connect((row + 1, col), (row + 1, col + 1));
+        }
+        if !inside(&region, r, c - 1) {
+            connect((row, col), (row + 1, col));
+        }
+        if !inside(&region, r, c + 1) {
+            connect((row, col + 1), (row + 1, col + 1));
+        }
+    }
+
+    const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+    let mut grid = openings
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(
+                    |&(north, south, east, west)| match (north, south, east, west) {
+                        (true, true, _, _) => '|',
+                        (_, _, true, true) => '-',
+                        (true, _, true, _) => 'L',
+                        (true, _, _, true) => 'J',
+                        (_, true, _, true) => '7',
+                        (_, true, true, _) => 'F',
+                        _ => JUNK[rng.gen_range(0..JUNK.len())],
+                    },
+                )
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Start on the top left corner of the loop, with only ground around it
+    // that isn't part of the loop, so that no other way leads back to it
+    let (row, col) = *members.iter().min().unwrap();
+    let (row, col) = (2 * row, 2 * col);
+    grid[row][col] = 'S';
+    if row > 0 {
+        grid[row - 1][col] = '.';
+    }
+    if col > 0 {
+        grid[row][col - 1] = '.';
+    }
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[aoc(year = 2023, day = 10, part1 = 8)]
+const COMPLEX_LOOP: &str = indoc! {"
+    ..F7.
+    .FJ|.
+    SJ.L7
+    |F--J
+    LJ...
+"};
+
+#[aoc(year = 2023, day = 10, part2 = 10)]
+const SAMPLE_WITH_GARBAGE_PIPES: &str = indoc! {"
+    FF7FSF7F7F7F7F7F---7
+    L|LJ||||||||||||F--J
+    FL-7LJLJ||||||LJL-77
+    F--JF--7||LJLJ7F7FJ-
+    L---JF-JLJ.||-FJLJJ7
+    |F|F-JF---7F7-L7L|7|
+    |FFJF7L7F-JF7|JL---7
+    7-L-JL7||F7|L7F-7F7|
+    L.L7LFJ|||||FJL7||LJ
+    L7JLJL-JLJLJL--JLJ.L
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    const SQUARE_LOOP: &str = indoc! {"
+        .....
+        .S-7.
+        .|.|.
+        .L-J.
+        .....
+    "};
+
+    const FOUR_INNER_TILES: &str = indoc! {"
+        ...........
+        .S-------7.
+        .|F-----7|.
+        .||.....||.
+        .||.....||.
+        .|L-7.F-J|.
+        .|..|.|..|.
+        .L--J.L--J.
+        ...........
+    "};
+
+    const FOUR_INNER_TILES_NO_PATH: &str = indoc! {"
+        ..........
+        .S------7.
+        .|F----7|.
+        .||....||.
+        .||....||.
+        .|L-7F-J|.
+        .|..||..|.
+        .L--JL--J.
+        ..........
+    "};
+
+    const LARGE_SAMPLE: &str = indoc! {"
+        .F----7F7F7F7F-7....
+        .|F--7||||||||FJ....
+        .||.FJ||||||||L7....
+        FJL7L7LJLJ||LJ.L-7..
+        L--J.L7...LJS7F-7L7.
+        ....F-J..F7FJ|L7L7L7
+        ....L7.F7||L7|.L7L7|
+        .....|FJLJ|FJ|F7|.LJ
+        ....FJL-7.||.||||...
+        ....L---J.LJ.LJLJ...
+    "};
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(find_loop_length(&parse_input(SQUARE_LOOP).unwrap()), 4);
+        assert_eq!(find_loop_length(&parse_input(COMPLEX_LOOP).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(count_insides(&parse_input(SQUARE_LOOP).unwrap()), 1);
+        assert_eq!(count_insides(&parse_input(FOUR_INNER_TILES).unwrap()), 4);
+        assert_eq!(
+            count_insides(&parse_input(FOUR_INNER_TILES_NO_PATH).unwrap()),
+            4
+        );
+        assert_eq!(count_insides(&parse_input(LARGE_SAMPLE).unwrap()), 8);
+        assert_eq!(
+            count_insides(&parse_input(SAMPLE_WITH_GARBAGE_PIPES).unwrap()),
+            10
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
+use std::str::FromStr;
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Space {
+    Empty(usize),
+    Galaxy,
+}
+
+impl FromStr for Space {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Space::Empty(1)),
+            "#" => Ok(Space::Galaxy),
+            _ => Err(ParseError::new(s, "one of '.', '#'")),
+        }
+    }
+}
+
+fn vastly_expand_space(space: &[Vec<Space>], multiplier: usize) -> Vec<Vec<Space>> {
+    let mut expanded_lines: Vec<Vec<Space>> = vec![];
+    for line in space {
+        let new_line = if line.iter().all(|s| matches!(s, Space::Empty(_))) {
+            line.iter().map(|_| Space::Empty(multiplier)).collect()
+        } else {
+            line.clone()
+        };
+        expanded_lines.push(new_line);
+    }
+    for col_idx in (0..expanded_lines.first().map_or(0, Vec::len)).rev() {
+        let all_empty = expanded_lines
+            .iter()
+            .all(|l| matches!(l[col_idx], Space::Empty(_)));
+        if all_empty {
+            for line in expanded_lines.iter_mut() {
+                line[col_idx] = Space::Empty(multiplier)
+            }
+        }
+    }
+    expanded_lines
+}
+
+fn make_pairs(galaxy_count: usize) -> Vec<(usize, usize)> {
+    (0..galaxy_count)
+        .flat_map(|i| (i + 1..galaxy_count).map(move |j| (i, j)))
+        .collect()
+}
+
+fn find_galaxies(space: &[Vec<Space>]) -> Vec<(usize, usize)> {
+    space
+        .iter()
+        .enumerate()
+        .flat_map(|(row_idx, row)| {
+            row.iter()
+                .enumerate()
+                .filter_map(|(col_idx, elem)| {
+                    if *elem == Space::Galaxy {
+                        return Some((row_idx, col_idx));
+                    };
+
+                    None
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn sum_distances(space: &[Vec<Space>]) -> usize {
+    let galaxies = find_galaxies(space);
+    make_pairs(galaxies.len())
+        .iter()
+        .map(|(l, r)| {
+            let lhs = galaxies[*l];
+            let rhs = galaxies[*r];
+            let min_x = lhs.1.min(rhs.1);
+            let max_x = lhs.1.max(rhs.1);
+            let min_y = lhs.0.min(rhs.0);
+            let max_y = lhs.0.max(rhs.0);
+            let empties_y = (min_y..max_y)
+                .map(|y| {
+                    space[y]
+                        .iter()
+                        .find_map(|e| match e {
+                            Space::Empty(mul) => Some(*mul),
+                            _ => None,
+                        })
+                        // Only galaxies, so certainly not expanded
+                        .unwrap_or(1)
+                })
+                .sum::<usize>();
+            let empties_x = (min_x..max_x)
+                .map(|x| {
+                    space
+                        .iter()
+                        .find_map(|l| match l[x] {
+                            Space::Empty(mul) => Some(mul),
+                            _ => None,
+                        })
+                        .unwrap_or(1)
+                })
+                .sum::<usize>();
+            empties_y + empties_x
+        })
+        .sum()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 11)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<Space>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::grid(input, str::parse)
+    }
+
+    fn part1(space: &Self::Parsed) -> Self::Part1 {
+        sum_distances(&vastly_expand_space(space, 2))
+    }
+
+    fn part2(space: &Self::Parsed) -> Self::Part2 {
+        sum_distances(&vastly_expand_space(space, 1000000))
+    }
+}
+
+#[aoc(year = 2023, day = 11, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Pairing up galaxies needs at least two of them, so two cells at least
+    let size = size.max(2);
+    // Galaxies stay out of some rows and columns, which then get expanded
+    let rows = (0..size).map(|_| rng.gen_bool(0.85)).collect::<Vec<_>>();
+    let columns = (0..size).map(|_| rng.gen_bool(0.85)).collect::<Vec<_>>();
+    let mut space = rows
+        .iter()
+        .map(|&row| {
+            columns
+                .iter()
+                .map(|&column| row && column && rng.gen_bool(0.05))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    while space.iter().flatten().filter(|&&galaxy| galaxy).count() < 2 {
+        space[rng.gen_range(0..size)][rng.gen_range(0..size)] = true;
+    }
+    space
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&galaxy| if galaxy { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[aoc(year = 2023, day = 11, part1 = 374)]
+const SAMPLE: &str = indoc! {"
+    ...#......
+    .......#..
+    #.........
+    ..........
+    ......#...
+    .#........
+    .........#
+    ..........
+    .......#..
+    #...#.....
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Solver::parse("#.\n..\n").unwrap(),
+            vec![
+                vec![Space::Galaxy, Space::Empty(1)],
+                vec![Space::Empty(1), Space::Empty(1)]
+            ]
+        );
+        assert!(Solver::parse("#.X\n").is_err());
+        assert!(Solver::parse("#..\n.#\n").is_err());
+    }
+
+    #[test]
+    fn test_make_pairs() {
+        assert_eq!(make_pairs(0), vec![]);
+        assert_eq!(make_pairs(1), vec![]);
+        assert_eq!(make_pairs(3), vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(
+            make_pairs(4),
+            vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),]
+        );
+        assert_eq!(
+            make_pairs(find_galaxies(&vastly_expand_space(&Solver::parse(SAMPLE).unwrap(), 2)).len()).len(),
+            36
+        );
+    }
+
+    #[test]
+    fn test_sum_distances() {
+        assert_eq!(sum_distances(&vastly_expand_space(&Solver::parse(SAMPLE).unwrap(), 2)), 374);
+        assert_eq!(sum_distances(&vastly_expand_space(&Solver::parse(SAMPLE).unwrap(), 10)), 1030);
+        assert_eq!(sum_distances(&vastly_expand_space(&Solver::parse(SAMPLE).unwrap(), 100)), 8410);
+        // Rows and columns full of galaxies aren't expanded
+        assert_eq!(sum_distances(&vastly_expand_space(&Solver::parse("##\n#.\n").unwrap(), 2)), 4);
+    }
+}
@@ -1,6 +1,16 @@
+use aoc::aoc;
 use cached::proc_macro::cached;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
-const INPUT: &str = include_str!("day_12.txt");
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 enum SpringStatus {
@@ -9,45 +19,64 @@ enum SpringStatus {
     Unknown,
 }
 
-impl From<char> for SpringStatus {
-    fn from(value: char) -> Self {
-        match value {
-            '.' => Self::Healthy,
-            '?' => Self::Unknown,
-            '#' => Self::Damaged,
-            _ => panic!(),
+impl FromStr for SpringStatus {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Self::Healthy),
+            "?" => Ok(Self::Unknown),
+            "#" => Ok(Self::Damaged),
+            _ => Err(ParseError::new(s, "one of '.', '?', '#'")),
         }
     }
 }
 
-impl ToString for SpringStatus {
-    fn to_string(&self) -> String {
+impl Display for SpringStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SpringStatus::Unknown => "?".to_string(),
-            SpringStatus::Damaged => "#".to_string(),
-            SpringStatus::Healthy => ".".to_string(),
+            SpringStatus::Unknown => f.write_str("?"),
+            SpringStatus::Damaged => f.write_str("#"),
+            SpringStatus::Healthy => f.write_str("."),
         }
     }
 }
 
-fn parse_line<S: Into<String>>(line: S) -> (Vec<SpringStatus>, Vec<usize>) {
-    let s = line.into();
-    let (springs, arrangements) = s.split_once(' ').unwrap();
-    (
-        springs.chars().map(SpringStatus::from).collect(),
-        arrangements
-            .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect(),
-    )
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    springs: Vec<SpringStatus>,
+    /// Lengths of the runs of damaged springs
+    runs: Vec<usize>,
+}
+
+fn parse_line(line: &str) -> Result<Row, ParseError> {
+    let (springs, runs) = parse::split_once(line, " ")?;
+    if springs.is_empty() {
+        return Err(ParseError::new(springs, "at least one spring"));
+    }
+    let springs = springs
+        .char_indices()
+        .map(|(idx, c)| springs[idx..idx + c.len_utf8()].parse())
+        .collect::<Result<_, _>>()?;
+    let runs = runs
+        .split(',')
+        .map(|run| match parse::number(run)? {
+            0 => Err(ParseError::new(run, "a run of at least one damaged spring")),
+            length => Ok(length),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Row { springs, runs })
 }
 
-fn unfold(line: &str) -> String {
-    let (springs, arrangements) = line.split_once(' ').unwrap();
-    let unfolded_springs = (0..5).map(|_| springs).collect::<Vec<_>>().join("?");
-    let unfolded_arrangements = (0..5).map(|_| arrangements).collect::<Vec<_>>().join(",");
+fn parse_input(input: &str) -> Result<Vec<Row>, ParseError> {
+    input.lines().map(parse_line).collect()
+}
 
-    [unfolded_springs, unfolded_arrangements].join(" ")
+fn unfold(row: &Row) -> Row {
+    Row {
+        springs: vec![row.springs.clone(); 5].join(&SpringStatus::Unknown),
+        runs: row.runs.repeat(5),
+    }
 }
 
 fn count_one(springs: &[SpringStatus], runs: &[usize]) -> usize {
@@ -130,45 +159,78 @@ fn count_one_helper(springs: &[SpringStatus], runs: &[usize], current: Option<us
     }
 }
 
-fn count_all(input: &str, pre_parse: fn(&str) -> String) -> usize {
-    input
-        .lines()
-        .map(|line| {
-            let (springs, arrangements) = parse_line(pre_parse(line));
-            count_one(&springs, &arrangements)
-        })
+fn count_all<'a>(rows: impl IntoIterator<Item = &'a Row>) -> usize {
+    rows.into_iter()
+        .map(|row| count_one(&row.springs, &row.runs))
         .sum()
 }
 
-pub fn print_solution() {
-    println!(
-        "Number of arrangements: {}",
-        count_all(INPUT, |i| i.to_string())
-    );
-    println!(
-        "Number of unfolded arrangements: {}",
-        count_all(INPUT, unfold)
-    );
+pub struct Solver;
+
+#[aoc(year = 2023, day = 12)]
+impl Solution for Solver {
+    type Parsed = Vec<Row>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(rows: &Self::Parsed) -> Self::Part1 {
+        count_all(rows)
+    }
+
+    fn part2(rows: &Self::Parsed) -> Self::Part2 {
+        count_all(&rows.iter().map(unfold).collect::<Vec<_>>())
+    }
 }
 
+#[aoc(year = 2023, day = 12, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            // Lay out the damaged runs first, then hide some of the springs
+            let length = rng.gen_range(4..=20);
+            let mut springs = String::new();
+            let mut runs = vec![];
+            while springs.len() < length || runs.is_empty() {
+                springs.push_str(&".".repeat(rng.gen_range(0..=3)));
+                let run = rng.gen_range(1..=length.saturating_sub(springs.len()).clamp(1, 5));
+                springs.push_str(&"#".repeat(run));
+                springs.push('.');
+                runs.push(run.to_string());
+            }
+            let springs = springs
+                .chars()
+                .map(|spring| match rng.gen_bool(0.4) {
+                    true => '?',
+                    false => spring,
+                })
+                .collect::<String>();
+            format!("{springs} {}\n", runs.join(","))
+        })
+        .collect()
+}
+
+#[aoc(year = 2023, day = 12, part1 = 21, part2 = 525152)]
+const SAMPLE: &str = indoc! {"
+    ???.### 1,1,3
+    .??..??...?##. 1,1,3
+    ?#?#?#?#?#?#?#? 1,3,1,6
+    ????.#...#... 4,1,1
+    ????.######..#####. 1,6,5
+    ?###???????? 3,2,1
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        ???.### 1,1,3
-        .??..??...?##. 1,1,3
-        ?#?#?#?#?#?#?#? 1,3,1,6
-        ????.#...#... 4,1,1
-        ????.######..#####. 1,6,5
-        ?###???????? 3,2,1
-    "};
-
     #[test]
     fn test_count_one() {
-        let make_slice = |s: &str| s.chars().map(SpringStatus::from).collect::<Vec<_>>();
+        let make_slice = |s: &str| parse_line(&format!("{s} 1")).unwrap().springs;
         assert_eq!(count_one(&make_slice("???"), &[1]), 3);
         assert_eq!(count_one(&make_slice("??"), &[2]), 1);
         assert_eq!(count_one(&make_slice("?"), &[1]), 1);
@@ -177,18 +239,10 @@ mod test {
         assert_eq!(count_one(&make_slice("?###????????"), &[5]), 2);
 
         assert_eq!(
-            SAMPLE
-                .lines()
-                .map(|l| {
-                    let (springs, arrs) = l.split_once(' ').unwrap();
-                    count_one(
-                        &make_slice(springs),
-                        &arrs
-                            .split(',')
-                            .map(|n| n.parse().unwrap())
-                            .collect::<Vec<_>>(),
-                    )
-                })
+            parse_input(SAMPLE)
+                .unwrap()
+                .iter()
+                .map(|row| count_one(&row.springs, &row.runs))
                 .collect::<Vec<_>>(),
             vec![1, 4, 1, 1, 4, 10]
         );
@@ -200,16 +254,36 @@ mod test {
         assert_eq!(count_one(&make_slice("?###????????"), &[3, 2, 1]), 10);
     }
 
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_line("#.? 1,3").unwrap(),
+            Row {
+                springs: vec![
+                    SpringStatus::Damaged,
+                    SpringStatus::Healthy,
+                    SpringStatus::Unknown
+                ],
+                runs: vec![1, 3],
+            }
+        );
+        assert!(parse_line("???.### 1,1,x").is_err());
+        assert!(parse_line("#.X 1").is_err());
+        assert!(parse_line("#? 0").is_err());
+        assert!(parse_line(" 1").is_err());
+        assert!(parse_line("#?").is_err());
+    }
+
     #[test]
     fn test_count_all() {
-        assert_eq!(count_all(SAMPLE, |i| i.to_string()), 21);
+        assert_eq!(count_all(&parse_input(SAMPLE).unwrap()), 21);
     }
 
     #[test]
     fn test_count_unfolded() {
         let count = |s: &str| {
-            let line = parse_line(unfold(s));
-            count_one(&line.0, &line.1)
+            let row = unfold(&parse_line(s).unwrap());
+            count_one(&row.springs, &row.runs)
         };
 
         assert_eq!(count(".# 1"), 1);
@@ -221,6 +295,7 @@ mod test {
         assert_eq!(count("????.######..#####. 1,6,5"), 2500);
         assert_eq!(count("?###???????? 3,2,1"), 506250);
 
-        assert_eq!(count_all(SAMPLE, unfold), 525152);
+        let rows = parse_input(SAMPLE).unwrap();
+        assert_eq!(count_all(&rows.iter().map(unfold).collect::<Vec<_>>()), 525152);
     }
 }
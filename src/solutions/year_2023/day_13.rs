@@ -0,0 +1,226 @@
+use aoc::aoc;
+use indoc::indoc;
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+#[derive(Debug)]
+enum ReflectionPosition {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+type Pattern = Vec<Vec<char>>;
+
+/// Parses the patterns, making sure that each has a line of reflection both
+/// without and with a smudge
+fn parse_input(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input
+        .split("\n\n")
+        .filter(|field| !field.trim().is_empty())
+        .map(|field| {
+            let pattern = parse::grid(field, |s| match s {
+                "." => Ok('.'),
+                "#" => Ok('#'),
+                _ => Err(ParseError::new(s, "one of '.', '#'")),
+            })?;
+            for (mismatches, expected) in [
+                (0, "a pattern with a line of reflection"),
+                (1, "a pattern with a line of reflection after fixing a smudge"),
+            ] {
+                if find_reflection(&pattern, mismatches).is_none() {
+                    let first_line = field.lines().next().unwrap_or(field);
+                    return Err(ParseError::new(first_line, expected));
+                }
+            }
+            Ok(pattern)
+        })
+        .collect()
+}
+
+fn find_reflection(field: &[Vec<char>], expected_mismatches: usize) -> Option<ReflectionPosition> {
+    let row_reflection =
+        field
+            .iter()
+            .enumerate()
+            .tuple_windows()
+            .find_map(|((top_idx, _), (bot_idx, _))| {
+                let top_range = (0..=top_idx).rev();
+                let bot_range = bot_idx..field.len();
+
+                let mismatches = top_range
+                    .zip(bot_range)
+                    .map(|(l, r)| {
+                        field[l]
+                            .iter()
+                            .zip(field[r].clone())
+                            .filter(|(one, two)| **one != *two)
+                            .count()
+                    })
+                    .sum::<usize>();
+
+                if mismatches == expected_mismatches {
+                    Some(top_idx)
+                } else {
+                    None
+                }
+            });
+    if let Some(row) = row_reflection {
+        return Some(ReflectionPosition::Horizontal(row + 1));
+    }
+
+    let columns = field.first().map_or(0, Vec::len);
+
+    let column_reflection = (0..columns)
+        .tuple_windows()
+        .find_map(|(left_idx, right_idx)| {
+            let left_range = (0..=left_idx).rev();
+            let right_range = right_idx..columns;
+            let mismatches = left_range
+                .zip(right_range)
+                .map(|(l, r)| field.iter().filter(|row| row[l] != row[r]).count())
+                .sum::<usize>();
+
+            if mismatches == expected_mismatches {
+                Some(left_idx)
+            } else {
+                None
+            }
+        });
+
+    column_reflection.map(|col| ReflectionPosition::Vertical(col + 1))
+}
+
+fn summarize_reflections(patterns: &[Pattern], expected_mismatches: usize) -> usize {
+    patterns
+        .iter()
+        // Parsing made sure that every pattern has a reflection
+        .filter_map(|pattern| find_reflection(pattern, expected_mismatches))
+        .map(|reflection| match reflection {
+            ReflectionPosition::Horizontal(pos) => pos * 100,
+            ReflectionPosition::Vertical(pos) => pos,
+        })
+        .sum()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 13)]
+impl Solution for Solver {
+    type Parsed = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(patterns: &Self::Parsed) -> Self::Part1 {
+        summarize_reflections(patterns, 0)
+    }
+
+    fn part2(patterns: &Self::Parsed) -> Self::Part2 {
+        summarize_reflections(patterns, 1)
+    }
+}
+
+#[aoc(year = 2023, day = 13, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Index that `index` is reflected to by a line before `line`, if any
+    let mirror = |index: usize, line: usize, length: usize| {
+        (2 * line - 1)
+            .checked_sub(index)
+            .filter(|&mirrored| mirrored < length)
+    };
+    (0..size)
+        .map(|_| {
+            // Reflect the pattern along both a row and a column, then smudge a
+            // tile that only the row reflection covers
+            let (height, width) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+            let row = rng.gen_range(1..height);
+            let column = loop {
+                let column = rng.gen_range(1..width);
+                if 2 * column != width {
+                    break column;
+                }
+            };
+            let tiles = (0..height * width)
+                .map(|_| rng.gen_bool(0.5))
+                .collect::<Vec<_>>();
+            let mut pattern = (0..height)
+                .map(|r| {
+                    let r = r.min(mirror(r, row, height).unwrap_or(r));
+                    (0..width)
+                        .map(|c| tiles[r * width + c.min(mirror(c, column, width).unwrap_or(c))])
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let smudged_row = loop {
+                let r = rng.gen_range(0..height);
+                if mirror(r, row, height).is_some() {
+                    break r;
+                }
+            };
+            let smudged_column = loop {
+                let c = rng.gen_range(0..width);
+                if mirror(c, column, width).is_none() {
+                    break c;
+                }
+            };
+            pattern[smudged_row][smudged_column] ^= true;
+            pattern
+                .iter()
+                .map(|line| {
+                    line.iter()
+                        .map(|&rock| if rock { '#' } else { '.' })
+                        .collect::<String>()
+                        + "\n"
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+#[aoc(year = 2023, day = 13, part1 = 405, part2 = 400)]
+const SAMPLE: &str = indoc! {"
+    #.##..##.
+    ..#.##.#.
+    ##......#
+    ##......#
+    ..#.##.#.
+    ..##..##.
+    #.#.##.#.
+
+    #...##..#
+    #....#..#
+    ..##..###
+    #####.##.
+    #####.##.
+    ..##..###
+    #....#..#
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_something() {
+        let patterns = parse_input(SAMPLE).unwrap();
+        assert_eq!(summarize_reflections(&patterns, 0), 405);
+        assert_eq!(summarize_reflections(&patterns, 1), 400);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(parse_input("#.X\n").is_err());
+        assert!(parse_input("##.\n#.\n").is_err());
+        // Reflected between the rows, but not with a smudge
+        assert!(parse_input("#.\n#.\n").is_err());
+    }
+}
@@ -1,13 +1,13 @@
-use std::collections::HashSet;
-
+use aoc::aoc;
+use indoc::indoc;
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
 use crate::utils::{
     math_2d::{Direction, Point},
     Indexed2D, ToMatrix,
 };
-
-const INPUT: &str = include_str!("day_14.txt");
+use crate::{parse::ParseError, solutions::Solution};
 
 fn tilt(field: &mut [Vec<char>], direction: Direction) {
     let by_rows = if direction == Direction::North {
@@ -49,44 +49,30 @@ fn to_string(field: &[Vec<char>]) -> String {
     field.iter().map(|line| line.iter().join("")).join("\n")
 }
 
-fn spin_cycle(field: &mut [Vec<char>], seen: &mut HashSet<String>) -> bool {
+fn spin_cycle(field: &mut [Vec<char>]) {
     tilt(field, Direction::North);
     tilt(field, Direction::West);
     tilt(field, Direction::South);
     tilt(field, Direction::East);
-
-    !seen.insert(to_string(field))
 }
 
+/// Load after spinning `times` cycles. Once a state repeats, the load of the
+/// last cycle is looked up in the loop instead of spinning any further.
 fn spin_until(field: &mut [Vec<char>], times: usize) -> usize {
-    let mut seen = HashSet::new();
-    let mut found_loads = vec![];
+    let mut states = vec![];
+    let mut loads = vec![];
 
-    let mut start_idx: Option<usize> = None;
     for i in 0..times {
-        if spin_cycle(field, &mut seen) {
-            if let Some(first_double) = start_idx {
-                start_idx = Some(2 * first_double - i);
-                break;
-            } else {
-                start_idx = Some(i);
-                seen.clear()
-            }
-        }
-
-        if start_idx.is_none() {
-            found_loads.push(calculate_load(field));
+        spin_cycle(field);
+        let state = to_string(field);
+        if let Some(start) = states.iter().position(|previous| *previous == state) {
+            return loads[start + (times - 1 - start) % (i - start)];
         }
+        states.push(state);
+        loads.push(calculate_load(field));
     }
 
-    let (_, cycle) = found_loads.split_at(start_idx.unwrap() + 1);
-
-    *cycle
-        .iter()
-        .cycle()
-        .take((times - (start_idx.unwrap() + 1)) % cycle.len())
-        .last()
-        .unwrap()
+    calculate_load(field)
 }
 
 fn calculate_load(field: &[Vec<char>]) -> usize {
@@ -100,41 +86,66 @@ fn calculate_load(field: &[Vec<char>]) -> usize {
         .sum()
 }
 
-pub fn print_solution() {
-    let mut field = INPUT.matrix();
-    tilt(&mut field, Direction::North);
-    println!(
-        "Total load on north support beams: {}",
+pub struct Solver;
+
+#[aoc(year = 2023, day = 14)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.matrix())
+    }
+
+    fn part1(field: &Self::Parsed) -> Self::Part1 {
+        let mut field = field.clone();
+        tilt(&mut field, Direction::North);
         calculate_load(&field)
-    );
-
-    let mut spin_field = INPUT.matrix();
-    let result = spin_until(&mut spin_field, 1000000000);
-    println!(
-        "Total load on north support beams after a billion spin cycles: {}",
-        result
-    );
+    }
+
+    fn part2(field: &Self::Parsed) -> Self::Part2 {
+        let mut field = field.clone();
+        spin_until(&mut field, 1000000000)
+    }
+}
+
+#[aoc(year = 2023, day = 14, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen_range(0..10) {
+                    0 => 'O',
+                    1 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
 }
 
+#[aoc(year = 2023, day = 14, part1 = 136, part2 = 64)]
+const SAMPLE: &str = indoc! {"
+    O....#....
+    O.OO#....#
+    .....##...
+    OO.#O....O
+    .O.....O#.
+    O.#..O.#.#
+    ..O..#O..O
+    .......O..
+    #....###..
+    #OO..#....
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        O....#....
-        O.OO#....#
-        .....##...
-        OO.#O....O
-        .O.....O#.
-        O.#..O.#.#
-        ..O..#O..O
-        .......O..
-        #....###..
-        #OO..#....
-    "};
-
     const TILTED_SAMPLE: &str = indoc! {"
         OOOO.#.O..
         OO..#....#
@@ -190,11 +201,10 @@ mod test {
 
     #[test]
     fn test_cycle() {
-        let mut seen = HashSet::new();
         let mut regular = SAMPLE.matrix();
-        spin_cycle(&mut regular, &mut seen);
+        spin_cycle(&mut regular);
         assert_eq!(regular, CYCLED_ONCE.matrix());
-        spin_cycle(&mut regular, &mut seen);
+        spin_cycle(&mut regular);
         assert_eq!(regular, CYCLED_TWICE.matrix());
     }
 
@@ -203,4 +213,16 @@ mod test {
         let mut field = SAMPLE.matrix();
         assert_eq!(spin_until(&mut field, 1000000000), 64);
     }
+
+    #[test]
+    fn test_spin_until_matches_spinning() {
+        // The sample loops every 7 cycles from the third one on, so this covers
+        // remaining spins that are a multiple of the loop as well
+        let mut spun = SAMPLE.matrix();
+        for times in 1..=30 {
+            spin_cycle(&mut spun);
+            let mut field = SAMPLE.matrix();
+            assert_eq!(spin_until(&mut field, times), calculate_load(&spun), "{times}");
+        }
+    }
 }
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use aoc::aoc;
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Step {
+    /// The whole step, which is what part 1 hashes
+    text: String,
+    label: String,
+    /// Lens to put in the box of `label`, or `None` to remove it
+    focal_length: Option<u8>,
+}
+
+fn parse_step(step: &str) -> Result<Step, ParseError> {
+    let (label, focal_length) = match step.strip_suffix('-') {
+        Some(label) => (label, None),
+        None => {
+            let (label, focal_length) = parse::split_once(step, "=")?;
+            (label, Some(parse::number(focal_length)?))
+        }
+    };
+    Ok(Step {
+        text: step.to_string(),
+        label: label.to_string(),
+        focal_length,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|step| parse_step(step).map_err(|err| err.with_hint("steps look like `rn=1` or `cm-`")))
+        .collect()
+}
+
+fn hash(string: &str) -> usize {
+    string
+        .chars()
+        .fold(0usize, |hash, char| ((hash + char as usize) * 17) % 256)
+}
+
+fn hash_sequence(steps: &[Step]) -> usize {
+    steps.iter().map(|step| hash(&step.text)).sum()
+}
+
+const DEFAULT_VEC: VecDeque<(&str, u8)> = VecDeque::new();
+fn hashmap_algorithm(steps: &[Step]) -> usize {
+    let mut boxes: [VecDeque<(&str, u8)>; 256] = [DEFAULT_VEC; 256];
+    steps.iter().for_each(|step| {
+        let label = step.label.as_str();
+        let one_box = &mut boxes[hash(label)];
+        match step.focal_length {
+            None => {
+                if let Some(existing_idx) = (0..one_box.len()).find(|i| one_box[*i].0 == label) {
+                    one_box.remove(existing_idx);
+                }
+            }
+            Some(num) => match one_box.iter_mut().find(|(lbl, _)| *lbl == label) {
+                Some(existing) => *existing = (label, num),
+                None => one_box.push_back((label, num)),
+            },
+        }
+    });
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_number, one_box)| {
+            one_box
+                .iter()
+                .enumerate()
+                .map(|(lens_idx, lens)| (1 + box_number) * (1 + lens_idx) * lens.1 as usize)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 15)]
+impl Solution for Solver {
+    type Parsed = Vec<Step>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Parsed) -> Self::Part1 {
+        hash_sequence(steps)
+    }
+
+    fn part2(steps: &Self::Parsed) -> Self::Part2 {
+        hashmap_algorithm(steps)
+    }
+}
+
+#[aoc(year = 2023, day = 15, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Draw from a limited set of labels, so that lenses get replaced and removed
+    let labels = (0..size.max(1))
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    (0..size * 40)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            match rng.gen_bool(0.3) {
+                true => format!("{label}-"),
+                false => format!("{label}={}", rng.gen_range(1..=9)),
+            }
+        })
+        .join(",")
+        + "\n"
+}
+
+#[aoc(year = 2023, day = 15, part1 = 1320, part2 = 145)]
+const SAMPLE_SEQ: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const SAMPLE: &str = "HASH";
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(SAMPLE), 52);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_input("rn=1,cm-\n").unwrap(),
+            vec![
+                Step {
+                    text: "rn=1".to_string(),
+                    label: "rn".to_string(),
+                    focal_length: Some(1),
+                },
+                Step {
+                    text: "cm-".to_string(),
+                    label: "cm".to_string(),
+                    focal_length: None,
+                },
+            ]
+        );
+        assert!(parse_input("rn=x").is_err());
+        assert!(parse_input("rn").is_err());
+    }
+
+    #[test]
+    fn test_hash_sequence() {
+        assert_eq!(hash_sequence(&parse_input(SAMPLE_SEQ).unwrap()), 1320);
+    }
+
+    #[test]
+    fn test_hashmap_algorithm() {
+        assert_eq!(hashmap_algorithm(&parse_input(SAMPLE_SEQ).unwrap()), 145);
+    }
+}
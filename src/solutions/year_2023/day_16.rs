@@ -1,11 +1,14 @@
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
 use crate::utils::{
     math_2d::{Direction, Point},
     *,
 };
+use crate::{parse::ParseError, solutions::Solution};
 use std::collections::{HashSet, VecDeque};
 
-const INPUT: &str = include_str!("day_16.txt");
-
 fn bfs(
     start: Trace,
     matrix: &[Vec<char>],
@@ -84,13 +87,57 @@ fn best_trace(field: &[Vec<char>]) -> usize {
         .unwrap()
 }
 
-pub fn print_solution() {
-    let field = INPUT.matrix();
-    let rays = raytrace(&field, Trace::new(Point::origin(), Direction::East));
-    println!("Number of energized points: {}", rays.len());
-    println!("Best energized points: {}", best_trace(&field));
+pub struct Solver;
+
+#[aoc(year = 2023, day = 16)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.matrix())
+    }
+
+    fn part1(field: &Self::Parsed) -> Self::Part1 {
+        raytrace(field, Trace::new(Point::origin(), Direction::East)).len()
+    }
+
+    fn part2(field: &Self::Parsed) -> Self::Part2 {
+        best_trace(field)
+    }
+}
+
+#[aoc(year = 2023, day = 16, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const TILES: [char; 4] = ['/', '\\', '|', '-'];
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen_bool(0.1) {
+                    true => TILES[rng.gen_range(0..TILES.len())],
+                    false => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
 }
 
+#[aoc(year = 2023, day = 16, part1 = 46, part2 = 51)]
+const SAMPLE: &str = indoc! {r"
+    .|...\....
+    |.-.\.....
+    .....|-...
+    ........|.
+    ..........
+    .........\
+    ..../.\\..
+    .-.-/..|..
+    .|....-|.\
+    ..//.|....
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -98,19 +145,6 @@ mod test {
     use itertools::Itertools;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {r"
-        .|...\....
-        |.-.\.....
-        .....|-...
-        ........|.
-        ..........
-        .........\
-        ..../.\\..
-        .-.-/..|..
-        .|....-|.\
-        ..//.|....
-    "};
-
     const SAMPLE_TRACED: &str = indoc! {r"
         ######....
         .#...#....
@@ -1,11 +1,17 @@
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
 use crate::utils::*;
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
 use std::{
     cmp::Ordering,
     collections::{BinaryHeap, HashSet},
 };
 
-const INPUT: &str = include_str!("day_17.txt");
-
 #[derive(Debug, PartialEq, Eq)]
 struct Path {
     // traces: List<Trace>,
@@ -132,43 +138,66 @@ fn find_path(matrix: &[Vec<usize>], min_steps: usize, max_steps: usize) -> Optio
     best_solution
 }
 
-pub fn print_solution() {
-    let city = INPUT.matrix_parse(|c| c.to_digit(10).unwrap() as usize);
-    println!(
-        "Least possible heat loss: {}",
-        find_path(&city, 1, 3).map(|s| s.cost).unwrap()
-    );
-    println!(
-        "Least possible heat loss with the ultra crucible: {}",
-        find_path(&city, 4, 10).map(|s| s.cost).unwrap()
-    );
+pub struct Solver;
+
+#[aoc(year = 2023, day = 17)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<usize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::matrix(input, parse::number)
+    }
+
+    fn part1(city: &Self::Parsed) -> Self::Part1 {
+        find_path(city, 1, 3).map(|s| s.cost).unwrap()
+    }
+
+    fn part2(city: &Self::Parsed) -> Self::Part2 {
+        find_path(city, 4, 10).map(|s| s.cost).unwrap()
+    }
 }
 
+#[aoc(year = 2023, day = 17, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Ultra crucibles have to go at least four blocks to reach the end
+    let size = size.max(5);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from_digit(rng.gen_range(1..=9), 10).unwrap())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[aoc(year = 2023, day = 17, part1 = 102, part2 = 94)]
+const SAMPLE: &str = indoc! {"
+    2413432311323
+    3215453535623
+    3255245654254
+    3446585845452
+    4546657867536
+    1438598798454
+    4457876987766
+    3637877979653
+    4654967986887
+    4564679986453
+    1224686865563
+    2546548887735
+    4322674655533
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        2413432311323
-        3215453535623
-        3255245654254
-        3446585845452
-        4546657867536
-        1438598798454
-        4457876987766
-        3637877979653
-        4654967986887
-        4564679986453
-        1224686865563
-        2546548887735
-        4322674655533
-    "};
-
     #[test]
     fn test_pathing() {
-        let city = SAMPLE.matrix_parse(|c| c.to_digit(10).unwrap() as usize);
+        let city = Solver::parse(SAMPLE).unwrap();
         assert_eq!(find_path(&city, 1, 3).map(|path| { path.cost }), Some(102));
         assert_eq!(find_path(&city, 4, 10).map(|path| { path.cost }), Some(94));
     }
@@ -0,0 +1,223 @@
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
+use crate::utils::{Direction, Vector2D};
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub struct Instruction {
+    direction: Direction,
+    count: usize,
+
+    color_count: usize,
+    color_direction: Direction,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, count, color) = s
+            .split_ascii_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(s, "a direction, a count and a color"))?;
+
+        let color = parse::strip_suffix(parse::strip_prefix(color, "(#")?, ")")?;
+        if color.len() != 6 || !color.is_ascii() {
+            return Err(ParseError::new(color, "six hex digits"));
+        }
+        let (hex_meters, dir_code) = color.split_at(5);
+
+        Ok(Self {
+            direction: direction.parse()?,
+            count: parse::number(count)?,
+            color_count: usize::from_str_radix(hex_meters, 16)
+                .map_err(|_| ParseError::new(hex_meters, "a hex number"))?,
+            // direction to dig: 0 means R, 1 means D, 2 means L, and 3 means U.
+            color_direction: match dir_code {
+                "0" => Direction::East,
+                "1" => Direction::South,
+                "2" => Direction::West,
+                "3" => Direction::North,
+                _ => return Err(ParseError::new(dir_code, "a direction between 0 and 3")),
+            },
+        })
+    }
+}
+
+fn calculate_area(instructions: &[(Direction, usize)]) -> isize {
+    let points = lengths_to_points(instructions);
+
+    points
+        .iter()
+        .tuple_windows()
+        .fold(0, |sum, (left, right)| {
+            sum + (left.y + right.y) * (left.x - right.x) / 2
+        })
+        .abs()
+        + instructions
+            .iter()
+            .map(|&(_, count)| count as isize)
+            .sum::<isize>()
+            / 2
+        + 1
+}
+
+fn lengths_to_points(instructions: &[(Direction, usize)]) -> Vec<Vector2D<isize>> {
+    let mut result = vec![Vector2D::origin()];
+
+    for &(direction, count) in instructions {
+        let last_point = result[result.len() - 1];
+        let direction: Vector2D<isize> = direction.into();
+        result.push(last_point + direction * (count) as isize)
+    }
+
+    result
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 18)]
+impl Solution for Solver {
+    type Parsed = Vec<Instruction>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed) -> Self::Part1 {
+        calculate_area(
+            &instructions
+                .iter()
+                .map(|ins| (ins.direction, ins.count))
+                .collect_vec(),
+        )
+    }
+
+    fn part2(instructions: &Self::Parsed) -> Self::Part2 {
+        calculate_area(
+            &instructions
+                .iter()
+                .map(|ins| (ins.color_direction, ins.color_count))
+                .collect_vec(),
+        )
+    }
+}
+
+#[aoc(year = 2023, day = 18, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Both plans dig the outline of columns of random widths that reach up
+    // and down by random amounts, so that neither outline crosses itself
+    let columns = size.max(1);
+    let plan = |rng: &mut StdRng, max: isize| {
+        let distinct = |rng: &mut StdRng| {
+            let mut heights = vec![rng.gen_range(1..=max)];
+            while heights.len() < columns {
+                let height = rng.gen_range(1..=max);
+                if height != heights[heights.len() - 1] {
+                    heights.push(height);
+                }
+            }
+            heights
+        };
+        let (tops, bottoms) = (distinct(rng), distinct(rng));
+        let widths = (0..columns)
+            .map(|_| rng.gen_range(1..=max))
+            .collect::<Vec<_>>();
+        let vertical = |change: isize| match change > 0 {
+            true => (Direction::North, change as usize),
+            false => (Direction::South, -change as usize),
+        };
+        let mut steps = vec![vertical(tops[0] + bottoms[0])];
+        for column in 0..columns {
+            steps.push((Direction::East, widths[column] as usize));
+            if let Some(next) = tops.get(column + 1) {
+                steps.push(vertical(next - tops[column]));
+            }
+        }
+        steps.push(vertical(-tops[columns - 1] - bottoms[columns - 1]));
+        for column in (0..columns).rev() {
+            steps.push((Direction::West, widths[column] as usize));
+            if column > 0 {
+                steps.push(vertical(bottoms[column] - bottoms[column - 1]));
+            }
+        }
+        steps
+    };
+    let short = plan(rng, 10);
+    let long = plan(rng, 400000);
+    short
+        .into_iter()
+        .zip(long)
+        .map(|((direction, count), (color_direction, color_count))| {
+            let letter = match direction {
+                Direction::North => 'U',
+                Direction::South => 'D',
+                Direction::East => 'R',
+                _ => 'L',
+            };
+            let digit = match color_direction {
+                Direction::East => 0,
+                Direction::South => 1,
+                Direction::West => 2,
+                _ => 3,
+            };
+            format!("{letter} {count} (#{color_count:05x}{digit})\n")
+        })
+        .collect()
+}
+
+#[aoc(year = 2023, day = 18, part1 = 62, part2 = 952408144115)]
+const SAMPLE: &str = indoc! {"
+    R 6 (#70c710)
+    D 5 (#0dc571)
+    L 2 (#5713f0)
+    D 2 (#d2c081)
+    R 2 (#59c680)
+    D 2 (#411b91)
+    L 5 (#8ceee2)
+    U 2 (#caa173)
+    L 1 (#1b58a2)
+    U 2 (#caa171)
+    R 2 (#7807d2)
+    U 3 (#a77fa3)
+    L 2 (#015232)
+    U 2 (#7a21e3)
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_calculate_area() {
+        let instructions: Vec<Instruction> = SAMPLE.lines().map(|l| l.parse().unwrap()).collect();
+        assert_eq!(
+            calculate_area(
+                &instructions
+                    .iter()
+                    .map(|ins| (ins.direction, ins.count))
+                    .collect_vec()
+            ),
+            62
+        );
+
+        assert_eq!(
+            calculate_area(
+                &instructions
+                    .iter()
+                    .map(|ins| (ins.color_direction, ins.color_count))
+                    .collect_vec()
+            ),
+            952408144115
+        );
+    }
+}
@@ -1,15 +1,19 @@
 use std::{
-    collections::{HashMap, VecDeque},
-    num::ParseIntError,
+    collections::{HashMap, HashSet, VecDeque},
     ops::RangeInclusive,
     str::FromStr,
 };
 
+use aoc::aoc;
+use indoc::indoc;
 use itertools::{FoldWhile, Itertools};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 
 use crate::utils::{RangeLen, RangeSplit};
-
-const INPUT: &str = include_str!("day_19.txt");
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
 
 #[derive(Clone)]
 enum Destination {
@@ -69,26 +73,46 @@ impl RuleInstruction {
     }
 }
 
+/// Parses one of the categories `x`, `m`, `a` or `s` at the start of `s`
+fn parse_category(s: &str) -> Result<char, ParseError> {
+    match s.as_bytes().first() {
+        Some(&category @ (b'x' | b'm' | b'a' | b's')) => Ok(category as char),
+        _ => Err(ParseError::new(s, "'x', 'm', 'a' or 's'")),
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = parse_category(s)?;
+        let operator = match s.as_bytes().get(1) {
+            Some(&operator @ (b'>' | b'<')) => operator as char,
+            _ => return Err(ParseError::new(&s[1..], "'>' or '<'")),
+        };
+        Ok(Operation {
+            key,
+            operator,
+            rhs_operand: parse::number(&s[2..])?,
+        })
+    }
+}
+
 impl FromStr for RuleInstruction {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // {a<2006:qkq}
         match s {
             "R" | "A" => Ok(RuleInstruction::destination(s.into())),
             s if s.contains(':') => {
-                let (condition, destination) = s.split_once(':').unwrap();
-                let key = condition.chars().nth(0).unwrap();
-                let operator = condition.chars().nth(1).unwrap();
-                let rhs_operand = condition[2..condition.len()].parse()?;
-                Ok(RuleInstruction::operation(
-                    Operation {
-                        key,
-                        operator,
-                        rhs_operand,
-                    },
-                    destination.into(),
-                ))
+                let (condition, destination) = parse::split_once(s, ":")?;
+                let operation = condition.parse().map_err(|err: ParseError| {
+                    err.with_hint(
+                        "rules look like `a<2006:qkq`, or are just a workflow, `A` or `R`",
+                    )
+                })?;
+                Ok(RuleInstruction::operation(operation, destination.into()))
             }
             s => Ok(RuleInstruction::destination(s.into())),
         }
@@ -100,7 +124,7 @@ struct Workflow {
 }
 
 impl FromStr for Workflow {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Workflow {
@@ -121,36 +145,51 @@ impl Part {
 }
 
 impl FromStr for Part {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Part {
-            elems: HashMap::from_iter(
-                s.trim_matches('{')
-                    .trim_matches('}')
-                    .split(',')
-                    .map(|p| p.split_once('=').unwrap())
-                    .map(|(chr, val)| (chr.chars().next().unwrap(), val.parse().unwrap())),
-            ),
+            elems: parse::strip_suffix(parse::strip_prefix(s, "{")?, "}")?
+                .split(',')
+                .map(|p| {
+                    let (chr, val) = parse::split_once(p, "=")?;
+                    Ok((parse_category(chr)?, parse::number(val)?))
+                })
+                .collect::<Result<_, _>>()
+                .map_err(|err: ParseError| {
+                    err.with_hint("parts look like `{x=787,m=2655,a=1222,s=2876}`")
+                })?,
         })
     }
 }
 
-struct Sorter {
+pub struct Sorter {
     workflows: HashMap<String, Workflow>,
     parts: Vec<Part>,
 }
 
 impl FromStr for Sorter {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (workflow_lines, part_lines) = s.split_once("\n\n").ok_or(())?;
-        let workflows = HashMap::from_iter(workflow_lines.lines().map(|line| {
-            let (label, rest) = line.split_once('{').unwrap();
-            let rules_str = &rest[..rest.len() - 1];
-            (label.to_string(), rules_str.parse().unwrap())
-        }));
+        let (workflow_lines, part_lines) = s.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(&s[s.len()..], "an empty line between workflows and parts")
+        })?;
+        let workflows = workflow_lines
+            .lines()
+            .map(|line| {
+                let (label, rest) = parse::split_once(line, "{")?;
+                let rules_str = parse::strip_suffix(rest, "}")?;
+                Ok((label.to_string(), rules_str.parse()?))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|err: ParseError| err.with_hint("workflows look like `px{a<2006:qkq,rfg}`"))?;
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new(
+                &workflow_lines[workflow_lines.len()..],
+                "a workflow called 'in'",
+            ));
+        }
 
         let parts = part_lines
             .lines()
@@ -254,7 +293,7 @@ struct Node<'a> {
 }
 
 impl<'a> Node<'a> {
-    fn new(workflow: &Workflow) -> Node {
+    fn new(workflow: &Workflow) -> Node<'_> {
         Node {
             bounds: HashMap::from([
                 ('x', 1..=4000),
@@ -267,40 +306,114 @@ impl<'a> Node<'a> {
     }
 }
 
-pub fn print_solution() {
-    let sorter: Sorter = INPUT.parse().unwrap();
-    println!("Sum of accepted parts: {}", sorter.execute_workflows());
-    println!(
-        "All possible combinations of accepted parts: {}",
+pub struct Solver;
+
+#[aoc(year = 2023, day = 19)]
+impl Solution for Solver {
+    type Parsed = Sorter;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(sorter: &Self::Parsed) -> Self::Part1 {
+        sorter.execute_workflows()
+    }
+
+    fn part2(sorter: &Self::Parsed) -> Self::Part2 {
         sorter.determine_distinct_combinations()
-    );
+    }
 }
 
+#[aoc(year = 2023, day = 19, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Workflows form a tree below `in`, so that every part ends up accepted or
+    // rejected
+    let mut labels = HashSet::from(["in".to_string()]);
+    let mut pending = VecDeque::from(["in".to_string()]);
+    let mut workflows = vec![];
+    while let Some(label) = pending.pop_front() {
+        let mut destination = |rng: &mut StdRng| {
+            if labels.len() < size && rng.gen_bool(0.5) {
+                let label = loop {
+                    let label = (0..rng.gen_range(2..=3))
+                        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                        .collect::<String>();
+                    if labels.insert(label.clone()) {
+                        break label;
+                    }
+                };
+                pending.push_back(label.clone());
+                label
+            } else {
+                ["A", "R"][rng.gen_range(0..2)].to_string()
+            }
+        };
+        let mut rules = (0..rng.gen_range(1..=3))
+            .map(|_| {
+                let category = ['x', 'm', 'a', 's'][rng.gen_range(0..4)];
+                let operator = ['<', '>'][rng.gen_range(0..2)];
+                let value = rng.gen_range(2..4000);
+                format!("{category}{operator}{value}:{}", destination(rng))
+            })
+            .collect::<Vec<_>>();
+        rules.push(destination(rng));
+        workflows.push(format!("{label}{{{}}}\n", rules.join(",")));
+    }
+    workflows.shuffle(rng);
+
+    let parts = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}\n")
+        })
+        .collect::<String>();
+    format!("{}\n{parts}", workflows.concat())
+}
+
+#[aoc(year = 2023, day = 19, part1 = 19114, part2 = 167409079868000)]
+const SAMPLE: &str = indoc! {"
+    px{a<2006:qkq,m>2090:A,rfg}
+    pv{a>1716:R,A}
+    lnx{m>1548:A,A}
+    rfg{s<537:gd,x>2440:R,A}
+    qs{s>3448:A,lnx}
+    qkq{x<1416:A,crn}
+    crn{x>2662:A,R}
+    in{s<1351:px,qqz}
+    qqz{s>2770:qs,m<1801:hdj,R}
+    gd{a>3333:R,R}
+    hdj{m>838:A,pv}
+
+    {x=787,m=2655,a=1222,s=2876}
+    {x=1679,m=44,a=2067,s=496}
+    {x=2036,m=264,a=79,s=2244}
+    {x=2461,m=1339,a=466,s=291}
+    {x=2127,m=1623,a=2188,s=1013}
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        px{a<2006:qkq,m>2090:A,rfg}
-        pv{a>1716:R,A}
-        lnx{m>1548:A,A}
-        rfg{s<537:gd,x>2440:R,A}
-        qs{s>3448:A,lnx}
-        qkq{x<1416:A,crn}
-        crn{x>2662:A,R}
-        in{s<1351:px,qqz}
-        qqz{s>2770:qs,m<1801:hdj,R}
-        gd{a>3333:R,R}
-        hdj{m>838:A,pv}
-
-        {x=787,m=2655,a=1222,s=2876}
-        {x=1679,m=44,a=2067,s=496}
-        {x=2036,m=264,a=79,s=2244}
-        {x=2461,m=1339,a=466,s=291}
-        {x=2127,m=1623,a=2188,s=1013}
-    "};
+    #[test]
+    fn test_invalid_rule() {
+        let input = SAMPLE.replace("a>3333:R", "a=3333:R");
+        let err = input.parse::<Sorter>().err().unwrap().locate(&input);
+        assert_eq!(
+            err.diagnostic(),
+            indoc! {"
+                   |
+                10 | gd{a=3333:R,R}
+                   |     ^^^^^ expected '>' or '<'
+                   = hint: rules look like `a<2006:qkq`, or are just a workflow, `A` or `R`
+            "}
+        );
+    }
 
     #[test]
     fn test_sorting() {
@@ -3,12 +3,17 @@ use std::{
     str::FromStr,
 };
 
+use aoc::aoc;
+use indoc::indoc;
 use itertools::Itertools;
 use num::Integer;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 
 use crate::utils::OptionFlatMap;
-
-const INPUT: &str = include_str!("day_20.txt");
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
 
 #[derive(Clone, Copy, PartialEq)]
 enum State {
@@ -23,34 +28,45 @@ enum Module {
     Broadcast,
 }
 
-struct Machine {
+pub struct Machine {
     parsed: HashMap<String, (Module, Vec<String>)>,
 }
 
 impl FromStr for Machine {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parsed = HashMap::from_iter(s.lines().map(|line| {
-            let (module_description, adj_list) = line.split_once(" -> ").unwrap();
-            let (label, module) = match module_description {
-                "broadcaster" => ("broadcaster", Module::Broadcast),
-                f if f.chars().nth(0) == Some('%') => {
-                    (&f[1..f.len()], Module::FlipFlop(State::Off))
-                }
-                c if c.chars().nth(0) == Some('&') => {
-                    (&c[1..c.len()], Module::Conjunction(HashMap::new()))
-                }
-                desc => unreachable!("Unhandled description: {desc}"),
-            };
-            (
-                label.to_string(),
-                (
-                    module,
-                    adj_list.split(", ").map(|s| s.to_string()).collect_vec(),
-                ),
-            )
-        }));
+        let parsed = s
+            .lines()
+            .map(|line| {
+                let (module_description, adj_list) = parse::split_once(line, " -> ")?;
+                let (label, module) = match module_description {
+                    "broadcaster" => ("broadcaster", Module::Broadcast),
+                    f if f.chars().nth(0) == Some('%') => {
+                        (&f[1..f.len()], Module::FlipFlop(State::Off))
+                    }
+                    c if c.chars().nth(0) == Some('&') => {
+                        (&c[1..c.len()], Module::Conjunction(HashMap::new()))
+                    }
+                    desc => {
+                        return Err(ParseError::new(
+                            desc,
+                            "'broadcaster', or a module name prefixed with '%' or '&'",
+                        ))
+                    }
+                };
+                Ok((
+                    label.to_string(),
+                    (
+                        module,
+                        adj_list.split(", ").map(|s| s.to_string()).collect_vec(),
+                    ),
+                ))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|err: ParseError| {
+                err.with_hint("modules look like `%a -> b, c`, `&inv -> a` or `broadcaster -> a`")
+            })?;
 
         Ok(Machine { parsed })
     }
@@ -159,29 +175,91 @@ impl Machine {
     }
 }
 
-pub fn print_solution() {
-    let machine: Machine = INPUT.parse().unwrap();
-    println!("Pulses: {}", machine.push_button(1000, false));
-    println!(
-        "Button presses to reach rx: {}",
+pub struct Solver;
+
+#[aoc(year = 2023, day = 20)]
+impl Solution for Solver {
+    type Parsed = Machine;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(machine: &Self::Parsed) -> Self::Part1 {
+        machine.push_button(1000, false)
+    }
+
+    fn part2(machine: &Self::Parsed) -> Self::Part2 {
         machine.push_button(usize::MAX, true)
-    );
+    }
 }
 
+#[aoc(year = 2023, day = 20, generator)]
+fn generate(rng: &mut StdRng, _size: usize) -> String {
+    // Like the real inputs: the broadcaster starts four 12 bit counters, each
+    // of which makes a conjunction send a low pulse and reset it when reaching
+    // a prime. Those pulses get inverted and combined into the one to `rx`.
+    let mut names = (b'a'..=b'z')
+        .cartesian_product(b'a'..=b'z')
+        .map(|(first, second)| format!("{}{}", first as char, second as char))
+        .filter(|name| name != "rx")
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+    let combined = names.pop().unwrap();
+    let mut primes = vec![];
+    while primes.len() < 4 {
+        let candidate: usize = rng.gen_range(2049..4096) | 1;
+        if (3..candidate)
+            .step_by(2)
+            .take_while(|d| d * d <= candidate)
+            .all(|d| !candidate.is_multiple_of(d))
+            && !primes.contains(&candidate)
+        {
+            primes.push(candidate);
+        }
+    }
+
+    let mut modules = vec![format!("&{combined} -> rx")];
+    let mut starts = vec![];
+    for prime in primes {
+        let bits = (0..12).map(|_| names.pop().unwrap()).collect::<Vec<_>>();
+        let (counter, inverter) = (names.pop().unwrap(), names.pop().unwrap());
+        let mut resets = vec![bits[0].clone()];
+        for (bit, name) in bits.iter().enumerate() {
+            let mut outputs = bits.get(bit + 1).into_iter().cloned().collect::<Vec<_>>();
+            match prime >> bit & 1 {
+                1 => outputs.push(counter.clone()),
+                _ => resets.push(name.clone()),
+            }
+            modules.push(format!("%{name} -> {}", outputs.join(", ")));
+        }
+        resets.push(inverter.clone());
+        modules.push(format!("&{counter} -> {}", resets.join(", ")));
+        modules.push(format!("&{inverter} -> {combined}"));
+        starts.push(bits[0].clone());
+    }
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    modules.shuffle(rng);
+    modules.iter().map(|module| format!("{module}\n")).collect()
+}
+
+#[aoc(year = 2023, day = 20, part1 = 32000000)]
+const SIMPLE_SAMPLE: &str = indoc! {"
+    broadcaster -> a, b, c
+    %a -> b
+    %b -> c
+    %c -> inv
+    &inv -> a
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SIMPLE_SAMPLE: &str = indoc! {"
-        broadcaster -> a, b, c
-        %a -> b
-        %b -> c
-        %c -> inv
-        &inv -> a
-    "};
-
     const COMPLEX_SAMPLE: &str = indoc! {"
         broadcaster -> a
         %a -> inv, con
@@ -198,4 +276,19 @@ mod test {
         let complex: Machine = COMPLEX_SAMPLE.parse().unwrap();
         assert_eq!(complex.push_button(1000, false), 11687500);
     }
+
+    #[test]
+    fn test_invalid_module() {
+        let input = COMPLEX_SAMPLE.replace("%b -> con", "b -> con");
+        let err = input.parse::<Machine>().err().unwrap().locate(&input);
+        assert_eq!(
+            err.diagnostic(),
+            indoc! {"
+                  |
+                4 | b -> con
+                  | ^ expected 'broadcaster', or a module name prefixed with '%' or '&'
+                  = hint: modules look like `%a -> b, c`, `&inv -> a` or `broadcaster -> a`
+            "}
+        );
+    }
 }
@@ -1,24 +1,30 @@
+use aoc::aoc;
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
-use crate::utils::{Direction, Enumerable2D, Indexed2D, OptionFlatMap, ToMatrixParse, Vector2D};
-use std::collections::HashSet;
-
-const INPUT: &str = include_str!("day_21.txt");
+use crate::utils::{Direction, Enumerable2D, Indexed2D, OptionFlatMap, Vector2D};
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+use std::{collections::HashSet, str::FromStr};
 
 #[derive(Debug)]
-enum Tile {
+pub enum Tile {
     Rock,
     Plot,
     Start,
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
-        match c {
-            '.' => Tile::Plot,
-            '#' => Tile::Rock,
-            'S' => Tile::Start,
-            c => unreachable!("Unhandled char: {c}"),
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Tile::Plot),
+            "#" => Ok(Tile::Rock),
+            "S" => Ok(Tile::Start),
+            _ => Err(ParseError::new(s, "one of '.', '#', 'S'")),
         }
     }
 }
@@ -74,7 +80,7 @@ fn possible_steps(field: &[Vec<Tile>], step_count: usize, wrap: bool) -> usize {
         }
         new_set
     });
-    result.extend(if step_count % 2 == 0 {
+    result.extend(if step_count.is_multiple_of(2) {
         seen_odd
     } else {
         seen_even
@@ -108,22 +114,49 @@ fn estimate_steps(field: &[Vec<Tile>], step_count: usize) -> usize {
     (n * n * d / 2 + n * (a - d / 2) + c) as usize
 }
 
-pub fn print_solution() {
-    let field = INPUT.matrix_parse(Tile::from);
-    println!(
-        "Possible places after 64 steps: {}",
-        possible_steps(&field, 64, false)
-    );
-
-    println!(
-        "Possible places after 26501365 steps to infinity: {}",
-        estimate_steps(&field, 26_501_365)
-    );
+pub struct Solver;
+
+#[aoc(year = 2023, day = 21)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<Tile>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::matrix(input, str::parse)
+    }
+
+    fn part1(field: &Self::Parsed) -> Self::Part1 {
+        possible_steps(field, 64, false)
+    }
+
+    fn part2(field: &Self::Parsed) -> Self::Part2 {
+        estimate_steps(field, 26_501_365)
+    }
+}
+
+#[aoc(year = 2023, day = 21, generator)]
+fn generate(rng: &mut StdRng, _size: usize) -> String {
+    // The steps for part two only line up with gardens of the real size, with
+    // the start in the middle of a clear row and column and a clear border
+    const SIZE: usize = 131;
+    (0..SIZE)
+        .map(|y| {
+            (0..SIZE)
+                .map(|x| match (x, y) {
+                    (65, 65) => 'S',
+                    (0 | 65 | 130, _) | (_, 0 | 65 | 130) => '.',
+                    _ if rng.gen_bool(0.1) => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
-    use crate::utils::ToMatrixParse;
 
     use super::*;
     use indoc::indoc;
@@ -145,7 +178,7 @@ mod test {
 
     #[test]
     fn test_possible_steps_bounded() {
-        let field = SAMPLE.matrix_parse(Tile::from);
+        let field = Solver::parse(SAMPLE).unwrap();
 
         assert_eq!(possible_steps(&field, 6, false), 16)
     }
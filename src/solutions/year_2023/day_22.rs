@@ -1,16 +1,21 @@
+use aoc::aoc;
+use indoc::indoc;
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
 use crate::utils::{Point3D, RangeIntersection, Vector3};
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
 use std::{
     collections::{HashMap, HashSet, VecDeque},
     ops::{Add, RangeInclusive},
     str::FromStr,
 };
 
-const INPUT: &str = include_str!("day_22.txt");
-
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
-struct Shape {
+pub struct Shape {
     start: Point3D,
     end: Point3D,
 }
@@ -40,11 +45,14 @@ impl Shape {
 }
 
 impl FromStr for Shape {
-    type Err = String;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (a, b) = s.split_once('~').ok_or("No delimiter found")?;
-        let l: Point3D = a.parse()?;
-        let r: Point3D = b.parse()?;
+        let parse_ends = || -> Result<(Point3D, Point3D), ParseError> {
+            let (a, b) = parse::split_once(s, "~")?;
+            Ok((a.parse()?, b.parse()?))
+        };
+        let (l, r) = parse_ends()
+            .map_err(|err| err.with_hint("bricks look like `1,0,1~1,2,1`, i.e. `x,y,z~x,y,z`"))?;
         let (start, end) = if l.z < r.z { (l, r) } else { (r, l) };
         Ok(Self { start, end })
     }
@@ -61,11 +69,8 @@ impl Add<Vector3<isize>> for Shape {
     }
 }
 
-fn parse_shapes(input: &str) -> Vec<Shape> {
-    input
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect_vec()
+fn parse_shapes(input: &str) -> Result<Vec<Shape>, ParseError> {
+    input.lines().map(str::parse).collect()
 }
 
 fn z_sort(input: &[Shape]) -> Vec<Shape> {
@@ -179,7 +184,7 @@ fn count_chain_reaction(
         .filter(|shape| !disintegratable_shapes.contains(shape))
         .map(|shape| {
             let mut queue = VecDeque::from_iter(
-                bottom_to_top[&shape]
+                bottom_to_top[shape]
                     .iter()
                     .filter(|top| top_to_bottom[top].len() == 1),
             );
@@ -188,7 +193,7 @@ fn count_chain_reaction(
                 if !seen.insert(bottom) {
                     continue;
                 }
-                for top in &bottom_to_top[&bottom] {
+                for top in &bottom_to_top[bottom] {
                     if seen.is_superset(&top_to_bottom[top].iter().collect()) {
                         queue.push_back(top);
                     }
@@ -200,34 +205,77 @@ fn count_chain_reaction(
         .sum()
 }
 
-pub fn print_solution() {
-    let dropped = drop_shapes(&parse_shapes(INPUT));
-    println!("Dropped");
-    let (bottom_to_top, top_to_bottom) = build_adjacency_lists(&dropped);
-    let disintegratable = find_disintegratable(&dropped, &bottom_to_top, &top_to_bottom);
-    println!("Number of disintegratable boxes: {}", disintegratable.len());
-    println!(
-        "Number of  {}",
+pub struct Solver;
+
+#[aoc(year = 2023, day = 22)]
+impl Solution for Solver {
+    type Parsed = Vec<Shape>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_shapes(input)
+    }
+
+    fn part1(shapes: &Self::Parsed) -> Self::Part1 {
+        let dropped = drop_shapes(shapes);
+        let (bottom_to_top, top_to_bottom) = build_adjacency_lists(&dropped);
+        find_disintegratable(&dropped, &bottom_to_top, &top_to_bottom).len()
+    }
+
+    fn part2(shapes: &Self::Parsed) -> Self::Part2 {
+        let dropped = drop_shapes(shapes);
+        let (bottom_to_top, top_to_bottom) = build_adjacency_lists(&dropped);
+        let disintegratable = find_disintegratable(&dropped, &bottom_to_top, &top_to_bottom);
         count_chain_reaction(&dropped, &disintegratable, &bottom_to_top, &top_to_bottom)
-    );
+    }
 }
 
+#[aoc(year = 2023, day = 22, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Bricks get placed in a 10x10 column wherever they don't overlap another
+    let mut bricks: Vec<(String, Shape)> = vec![];
+    while bricks.len() < size * 12 {
+        let start = [
+            rng.gen_range(0..10),
+            rng.gen_range(0..10),
+            rng.gen_range(1..=size as isize * 3),
+        ];
+        let mut end = start;
+        let axis = rng.gen_range(0..3);
+        end[axis] += rng.gen_range(0..4);
+        if end[..2].iter().any(|&coordinate| coordinate >= 10) {
+            continue;
+        }
+        let line = format!(
+            "{},{},{}~{},{},{}",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        );
+        let shape = line.parse::<Shape>().unwrap();
+        if bricks.iter().all(|(_, other)| !shape.overlaps(other)) {
+            bricks.push((line, shape));
+        }
+    }
+    bricks.iter().map(|(line, _)| format!("{line}\n")).collect()
+}
+
+#[aoc(year = 2023, day = 22, part1 = 5, part2 = 7)]
+const SAMPLE: &str = indoc! {"
+    2,0,5~2,2,5
+    0,1,6~2,1,6
+    1,1,8~1,1,9
+    1,0,1~1,2,1
+    0,0,2~2,0,2
+    0,2,3~2,2,3
+    0,0,4~0,2,4
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        2,0,5~2,2,5
-        0,1,6~2,1,6
-        1,1,8~1,1,9
-        1,0,1~1,2,1
-        0,0,2~2,0,2
-        0,2,3~2,2,3
-        0,0,4~0,2,4
-    "};
-
     // This one has two indepedendent sub-graphs, where on one of them there are
     // two nodes at the bottom supporting the rest of the tower. Everything up
     // from that is the same.
@@ -270,11 +318,12 @@ mod test {
 
     #[test]
     fn test_dropping_shapes() {
-        let shapes = parse_shapes(SAMPLE);
+        let shapes = parse_shapes(SAMPLE).unwrap();
         let dropped = drop_shapes(&shapes);
         assert_eq!(
             dropped.iter().sorted_by_key(|x| x.start.z).collect_vec(),
             parse_shapes(DROPPED)
+                .unwrap()
                 .iter()
                 .sorted_by_key(|x| x.start.z)
                 .collect_vec()
@@ -299,7 +348,7 @@ mod test {
 
     #[test]
     fn test_find_disintegratable() {
-        let dropped = drop_shapes(&parse_shapes(SAMPLE));
+        let dropped = drop_shapes(&parse_shapes(SAMPLE).unwrap());
         let (bottom_to_top, top_to_bottom) = build_adjacency_lists(&dropped);
         assert_eq!(
             find_disintegratable(&dropped, &bottom_to_top, &top_to_bottom).len(),
@@ -309,14 +358,14 @@ mod test {
 
     #[test]
     fn test_chain_reaction() {
-        let shapes = drop_shapes(&parse_shapes(SAMPLE));
+        let shapes = drop_shapes(&parse_shapes(SAMPLE).unwrap());
         let (bottom_to_top, top_to_bottom) = build_adjacency_lists(&shapes);
         let disintegratable = find_disintegratable(&shapes, &bottom_to_top, &top_to_bottom);
         assert_eq!(
             count_chain_reaction(&shapes, &disintegratable, &bottom_to_top, &top_to_bottom),
             7
         );
-        let shapes = drop_shapes(&parse_shapes(THE_TWO_TOWERS));
+        let shapes = drop_shapes(&parse_shapes(THE_TWO_TOWERS).unwrap());
         let (bottom_to_top, top_to_bottom) = build_adjacency_lists(&shapes);
         let disintegratable = find_disintegratable(&shapes, &bottom_to_top, &top_to_bottom);
         assert_eq!(
@@ -324,4 +373,30 @@ mod test {
             8
         );
     }
+
+    #[test]
+    fn test_invalid_brick() {
+        let input = SAMPLE.replace("1,1,8~1,1,9", "1,1,8-1,1,9");
+        let err = parse_shapes(&input).err().unwrap().locate(&input);
+        assert_eq!(
+            err.diagnostic(),
+            indoc! {"
+                  |
+                3 | 1,1,8-1,1,9
+                  | ^^^^^^^^^^^ expected '~'
+                  = hint: bricks look like `1,0,1~1,2,1`, i.e. `x,y,z~x,y,z`
+            "}
+        );
+        let input = SAMPLE.replace("1,1,8~1,1,9", "1,1,8~1,x,9");
+        let err = parse_shapes(&input).err().unwrap().locate(&input);
+        assert_eq!(
+            err.diagnostic(),
+            indoc! {"
+                  |
+                3 | 1,1,8~1,x,9
+                  |         ^ expected a number
+                  = hint: bricks look like `1,0,1~1,2,1`, i.e. `x,y,z~x,y,z`
+            "}
+        );
+    }
 }
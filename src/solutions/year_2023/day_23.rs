@@ -1,27 +1,37 @@
+use aoc::aoc;
+use indoc::indoc;
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
-use crate::utils::{Direction, Enumerable2D, Indexed2D, Point, ToMatrixParse};
-use std::collections::{HashMap, HashSet, VecDeque};
-
-const INPUT: &str = include_str!("day_23.txt");
+use crate::utils::{Direction, Enumerable2D, Indexed2D, Point};
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    str::FromStr,
+};
 
 #[derive(Debug, PartialEq, Eq)]
-enum Tile {
+pub enum Tile {
     Path,
     Forest,
     Slope(Direction),
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
-        match c {
-            '.' => Tile::Path,
-            '#' => Tile::Forest,
-            '>' => Tile::Slope(Direction::East),
-            '<' => Tile::Slope(Direction::West),
-            'v' => Tile::Slope(Direction::South),
-            '^' => Tile::Slope(Direction::North),
-            c => unreachable!("Unhandled character: {c}"),
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Tile::Path),
+            "#" => Ok(Tile::Forest),
+            ">" => Ok(Tile::Slope(Direction::East)),
+            "<" => Ok(Tile::Slope(Direction::West)),
+            "v" => Ok(Tile::Slope(Direction::South)),
+            "^" => Ok(Tile::Slope(Direction::North)),
+            _ => Err(ParseError::new(s, "one of '.', '#', '>', '<', 'v', '^'")),
         }
     }
 }
@@ -114,7 +124,7 @@ fn to_adjacency_list(
                     .filter(|p| {
                         hiking_area
                             .get_point(*p)
-                            .map_or(false, |t| *t != Tile::Forest)
+                            .is_some_and(|t| *t != Tile::Forest)
                     })
                     .map(|p| (p, 1))
                     .collect(),
@@ -159,55 +169,101 @@ fn simplify_adjacency_list(
         .collect()
 }
 
-pub fn print_solution() {
-    let hiking_area = INPUT.matrix_parse(Tile::from);
-    println!(
-        "Longest hiking path: {}",
-        find_longest_path(&hiking_area, true)
-    );
-    println!(
-        "Longest hiking path with passable slopes: {}",
-        find_longest_path(&hiking_area, false)
-    );
+pub struct Solver;
+
+#[aoc(year = 2023, day = 23)]
+impl Solution for Solver {
+    type Parsed = Vec<Vec<Tile>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::matrix(input, str::parse)
+    }
+
+    fn part1(hiking_area: &Self::Parsed) -> Self::Part1 {
+        find_longest_path(hiking_area, true)
+    }
+
+    fn part2(hiking_area: &Self::Parsed) -> Self::Part2 {
+        find_longest_path(hiking_area, false)
+    }
 }
 
+#[aoc(year = 2023, day = 23, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Corridors run between junctions on a grid, with slopes leading away from
+    // the start on both ends of each one, like the real inputs
+    let junctions = (size / 20).clamp(2, 5);
+    let offsets = |rng: &mut StdRng| {
+        (0..junctions)
+            .scan(1, |offset, _| {
+                let current = *offset;
+                *offset += rng.gen_range(4..=12);
+                Some(current)
+            })
+            .collect::<Vec<usize>>()
+    };
+    let (rows, columns) = (offsets(rng), offsets(rng));
+    let (last_row, last_column) = (rows[junctions - 1], columns[junctions - 1]);
+    let mut grid = vec![vec!['#'; last_column + 2]; last_row + 2];
+    for (i, &row) in rows.iter().enumerate() {
+        for (j, &column) in columns.iter().enumerate() {
+            if let Some(&next) = columns.get(j + 1) {
+                grid[row][column + 1..next].fill('.');
+                grid[row][column + 1] = '>';
+                grid[row][next - 1] = '>';
+            }
+            if let Some(&next) = rows.get(i + 1) {
+                (row + 1..next).for_each(|r| grid[r][column] = '.');
+                grid[row + 1][column] = 'v';
+                grid[next - 1][column] = 'v';
+            }
+            grid[row][column] = '.';
+        }
+    }
+    grid[0][columns[0]] = '.';
+    grid[last_row + 1][last_column] = '.';
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[aoc(year = 2023, day = 23, part1 = 94, part2 = 154)]
+const SAMPLE: &str = indoc! {"
+    #.#####################
+    #.......#########...###
+    #######.#########.#.###
+    ###.....#.>.>.###.#.###
+    ###v#####.#v#.###.#.###
+    ###.>...#.#.#.....#...#
+    ###v###.#.#.#########.#
+    ###...#.#.#.......#...#
+    #####.#.#.#######.#.###
+    #.....#.#.#.......#...#
+    #.#####.#.#.#########v#
+    #.#...#...#...###...>.#
+    #.#.#v#######v###.###v#
+    #...#.>.#...>.>.#.###.#
+    #####v#.#.###v#.#.###.#
+    #.....#...#...#.#.#...#
+    #.#########.###.#.#.###
+    #...###...#...#...#.###
+    ###.###.#.###v#####v###
+    #...#...#.#.>.>.#.>.###
+    #.###.###.#.###.#.#v###
+    #.....###...###...#...#
+    #####################.#
+"};
+
 #[cfg(test)]
 mod test {
-    use crate::utils::ToMatrixParse;
-
     use super::*;
-    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        #.#####################
-        #.......#########...###
-        #######.#########.#.###
-        ###.....#.>.>.###.#.###
-        ###v#####.#v#.###.#.###
-        ###.>...#.#.#.....#...#
-        ###v###.#.#.#########.#
-        ###...#.#.#.......#...#
-        #####.#.#.#######.#.###
-        #.....#.#.#.......#...#
-        #.#####.#.#.#########v#
-        #.#...#...#...###...>.#
-        #.#.#v#######v###.###v#
-        #...#.>.#...>.>.#.###.#
-        #####v#.#.###v#.#.###.#
-        #.....#...#...#.#.#...#
-        #.#########.###.#.#.###
-        #...###...#...#...#.###
-        ###.###.#.###v#####v###
-        #...#...#.#.>.>.#.>.###
-        #.###.###.#.###.#.#v###
-        #.....###...###...#...#
-        #####################.#
-    "};
-
     #[test]
     fn test_find_longest_path() {
-        let hiking_area = SAMPLE.matrix_parse(Tile::from);
+        let hiking_area = Solver::parse(SAMPLE).unwrap();
         assert_eq!(find_longest_path(&hiking_area, true), 94);
         assert_eq!(find_longest_path(&hiking_area, false), 154);
     }
@@ -1,10 +1,16 @@
+use aoc::aoc;
+use indoc::indoc;
+use rand::{rngs::StdRng, Rng};
+
 use crate::utils::Vector3;
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
 use itertools::Itertools;
-use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
-
-const INPUT: &str = include_str!("day_24.txt");
+use std::{collections::HashSet, fmt::Display, ops::RangeInclusive, str::FromStr};
 
-struct Hailstone {
+pub struct Hailstone {
     p: Vector3<f64>,
     v: Vector3<f64>,
 }
@@ -48,12 +54,10 @@ impl Display for Hailstone {
 }
 
 impl FromStr for Hailstone {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (pos_str, vel_str) = s
-            .split_once(" @ ")
-            .ok_or("Missing '@' delimiter between pos and velocity")?;
+        let (pos_str, vel_str) = parse::split_once(s, " @ ")?;
 
         Ok(Hailstone {
             p: pos_str.parse()?,
@@ -82,7 +86,7 @@ fn check_intersections(hailstones: &[Hailstone], in_range: RangeInclusive<f64>)
         .count()
 }
 
-fn find_rock_throw(hailstones: &[Hailstone]) -> f64 {
+fn find_rock_throw(hailstones: &[Hailstone]) -> i64 {
     let first = &hailstones[0];
     let second = &hailstones[1];
     let search_range = 0..350;
@@ -138,40 +142,82 @@ fn find_rock_throw(hailstones: &[Hailstone]) -> f64 {
         })
         .expect("No rock found :(");
 
-    rock.p.x + rock.p.y + rock.p.z
+    // The coordinates are integers, but only up to floating point precision
+    (rock.p.x + rock.p.y + rock.p.z).round() as i64
+}
+
+pub struct Solver;
+
+#[aoc(year = 2023, day = 24)]
+impl Solution for Solver {
+    type Parsed = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(Hailstone::from_str)
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    fn part1(hailstones: &Self::Parsed) -> Self::Part1 {
+        check_intersections(hailstones, 200000000000000.0..=400000000000000.0)
+    }
+
+    fn part2(hailstones: &Self::Parsed) -> Self::Part2 {
+        find_rock_throw(hailstones)
+    }
 }
 
-pub fn print_solution() {
-    let hailstones = INPUT
-        .lines()
-        .map(Hailstone::from_str)
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap();
-    println!(
-        "Number of intersections in xy plane: {}",
-        check_intersections(&hailstones, 200000000000000.0..=400000000000000.0)
-    );
-
-    println!(
-        "Sum of coordinates of rock position: {}",
-        find_rock_throw(&hailstones)
-    );
+#[aoc(year = 2023, day = 24, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Work back from the rock to where each hailstone has to start so that the
+    // rock hits it, at a different time for each. The times are kept short
+    // enough for the rock's search to do exact math in floating point.
+    let cross = |a: [i64; 3], b: [i64; 3]| a[0] * b[1] - a[1] * b[0];
+    let rock_velocity = [(); 3].map(|_| rng.gen_range(-300..=300));
+    let rock = [(); 3].map(|_| rng.gen_range(100_000_000_000_000..=400_000_000_000_000));
+    let mut times = HashSet::new();
+    let mut relative_velocities = vec![];
+    let mut hailstones = String::new();
+    while relative_velocities.len() < size.max(5) * 3 {
+        let velocity = [(); 3].map(|_| rng.gen_range(-400..=400));
+        let relative = [0, 1, 2].map(|axis| velocity[axis] - rock_velocity[axis]);
+        // Paths parallel to the rock's, or to one of the first hailstones' as
+        // seen from the rock, don't pin down where the rock is
+        let time = rng.gen_range(100_000_000..2_000_000_000_i64);
+        if cross(velocity, rock_velocity) == 0
+            || relative_velocities
+                .iter()
+                .take(4)
+                .any(|&other| cross(relative, other) == 0)
+            || !times.insert(time)
+        {
+            continue;
+        }
+        let [x, y, z] = [0, 1, 2].map(|axis| rock[axis] - relative[axis] * time);
+        let [vx, vy, vz] = velocity;
+        hailstones += &format!("{x}, {y}, {z} @ {vx}, {vy}, {vz}\n");
+        relative_velocities.push(relative);
+    }
+    hailstones
 }
 
+#[aoc(year = 2023, day = 24, part2 = 47)]
+const SAMPLE: &str = indoc! {"
+    19, 13, 30 @ -2,  1, -2
+    18, 19, 22 @ -1, -1, -2
+    20, 25, 34 @ -2, -2, -4
+    12, 31, 28 @ -1, -2, -1
+    20, 19, 15 @  1, -5, -3
+"};
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    const SAMPLE: &str = indoc! {"
-        19, 13, 30 @ -2,  1, -2
-        18, 19, 22 @ -1, -1, -2
-        20, 25, 34 @ -2, -2, -4
-        12, 31, 28 @ -1, -2, -1
-        20, 19, 15 @  1, -5, -3
-    "};
-
     #[test]
     fn test_intersect() {
         let hailstones = SAMPLE
@@ -189,6 +235,6 @@ mod test {
             .map(Hailstone::from_str)
             .collect::<Result<Vec<_>, _>>()
             .unwrap();
-        assert_eq!(find_rock_throw(&hailstones), 47.0);
+        assert_eq!(find_rock_throw(&hailstones), 47);
     }
 }
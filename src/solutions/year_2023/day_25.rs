@@ -0,0 +1,230 @@
+use std::{
+    collections::{BTreeSet, HashSet, VecDeque},
+    rc::Rc,
+};
+
+use aoc::aoc;
+use indoc::indoc;
+use itertools::Itertools;
+use rand::{
+    rngs::StdRng,
+    seq::{IteratorRandom, SliceRandom},
+    Rng,
+};
+
+use crate::{
+    parse::{self, ParseError},
+    solutions::Solution,
+};
+
+type Edge = (Rc<str>, Rc<str>);
+
+fn parse_edges(input: &str) -> Result<Vec<Edge>, ParseError> {
+    let mut edges = vec![];
+    for line in input.lines() {
+        let (start, ends_str) = parse::split_once(line, ": ")?;
+        edges.extend(
+            ends_str
+                .split_ascii_whitespace()
+                .map(|end| (start.into(), end.into())),
+        );
+    }
+    Ok(edges)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Color {
+    Red,
+    White,
+}
+
+fn to_adjacencies(vertices: &[Rc<str>], edges: &[Edge]) -> Vec<Vec<usize>> {
+    let edges_indices = edges
+        .iter()
+        .map(|(l, r)| {
+            (
+                vertices.iter().position(|e| l == e).unwrap(),
+                vertices.iter().position(|e| e == r).unwrap(),
+            )
+        })
+        .collect_vec();
+
+    vertices
+        .iter()
+        .enumerate()
+        .map(|(idx, _)| {
+            edges_indices
+                .iter()
+                .filter_map(|(l, r)| {
+                    (*l == idx)
+                        .then_some(*r)
+                        .or_else(|| (*r == idx).then_some(*l))
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+fn form_groups(edges: &[Edge]) -> usize {
+    let vertices = edges
+        .iter()
+        .flat_map(|(l, r)| [l, r])
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect_vec();
+
+    let adj_list = to_adjacencies(&vertices, edges);
+
+    let mut rng = rand::thread_rng();
+    loop {
+        let mut colors = vec![None; vertices.len()];
+        let first = rng.gen_range(0..vertices.len());
+        let second = loop {
+            let n = rng.gen_range(0..vertices.len());
+            if n != first {
+                break n;
+            }
+        };
+        let mut queue = VecDeque::new();
+        queue.push_back((first, Color::White));
+        queue.push_back((second, Color::Red));
+        while let Some((idx, color)) = queue.pop_front() {
+            colors[idx] = Some(color);
+
+            adj_list[idx].iter().for_each(|other_idx| {
+                if colors[*other_idx].is_none() {
+                    queue.push_back((*other_idx, color));
+                }
+            });
+        }
+
+        let cut_count = adj_list
+            .iter()
+            .enumerate()
+            .map(|(left, neighbors)| {
+                let left_color = colors[left].unwrap();
+                neighbors
+                    .iter()
+                    .map(|right| {
+                        if colors[*right].unwrap() == left_color {
+                            0
+                        } else {
+                            1
+                        }
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>();
+        // We double count everything because the adjacency list has both
+        // forwards and backwards adjacencies
+        if cut_count == 6 {
+            let white_count = colors
+                .iter()
+                .filter(|&&c| c == Some(Color::White))
+                .collect_vec()
+                .len();
+            return white_count * (vertices.len() - white_count);
+        }
+    }
+}
+
+pub struct Solver;
+
+// There is no second puzzle on the last day, so only part 1 is registered
+#[aoc(year = 2023, day = 25, part = 1)]
+impl Solution for Solver {
+    type Parsed = Vec<Edge>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_edges(input)
+    }
+
+    fn part1(wires: &Self::Parsed) -> Self::Part1 {
+        form_groups(wires)
+    }
+
+    fn part2(_: &Self::Parsed) -> Self::Part2 {
+        unreachable!("day 25 has no part 2")
+    }
+}
+
+#[aoc(year = 2023, day = 25, generator)]
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Two groups in which every component has at least four wires, so that the
+    // three wires joining them are the only cut of that size
+    let size = size.max(4) * 5;
+    let groups = [(); 2].map(|_| rng.gen_range(size / 2..=size));
+    let mut names = vec![];
+    let mut seen = HashSet::new();
+    while names.len() < groups[0] + groups[1] {
+        let name = (0..3)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut wires = BTreeSet::new();
+    for (offset, group) in [(0, groups[0]), (groups[0], groups[1])] {
+        for component in offset..offset + group {
+            wires.insert((component, offset + (component + 1 - offset) % group));
+            let unconnected = (offset..offset + group)
+                .filter(|&other| {
+                    other != component
+                        && !wires.contains(&(component, other))
+                        && !wires.contains(&(other, component))
+                })
+                .choose_multiple(rng, 2);
+            wires.extend(unconnected.into_iter().map(|other| (component, other)));
+        }
+    }
+    let left = (0..groups[0]).choose_multiple(rng, 3);
+    let right = (groups[0]..names.len()).choose_multiple(rng, 3);
+    wires.extend(left.into_iter().zip(right));
+
+    let mut lines = wires
+        .into_iter()
+        .into_group_map()
+        .into_iter()
+        .map(|(component, others)| {
+            let others = others.iter().map(|&other| &names[other]).join(" ");
+            format!("{}: {others}\n", names[component])
+        })
+        .collect_vec();
+    lines.sort();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[aoc(year = 2023, day = 25, part1 = 54)]
+const SAMPLE: &str = indoc! {"
+    jqt: rhn xhk nvd
+    rsh: frs pzl lsr
+    xhk: hfx
+    cmg: qnr nvd lhk bvb
+    rhn: xhk bvb hfx
+    bvb: xhk hfx
+    pzl: lsr hfx nvd
+    qnr: nvd
+    ntq: jqt hfx bvb xhk
+    nvd: lhk
+    lsr: lhk
+    rzs: qnr cmg lsr rsh
+    frs: qnr lhk lsr
+"};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_form_groups() {
+        let wires = parse_edges(SAMPLE).unwrap();
+        assert_eq!(form_groups(&wires), 54)
+    }
+}
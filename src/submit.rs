@@ -0,0 +1,449 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answer::Answer,
+    config, input,
+    runner::{self, Part, Selection},
+};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// File every submitted answer and its verdict is recorded in
+    #[arg(long, default_value = "submissions.toml")]
+    history: PathBuf,
+}
+
+impl SubmitArgs {
+    /// Only a single answer can be submitted at a time
+    pub fn validate(&self) -> Result<(), String> {
+        if self.selection.example() {
+            return Err("only answers for the actual input can be submitted".to_string());
+        }
+        match (self.selection.days().len(), self.selection.parts().len()) {
+            (1, 1) => Ok(()),
+            _ => Err("submit needs a single --day and a --part".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The previous answer was submitted too recently
+    RateLimited,
+    /// The part is either already solved or not unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the answer is known to be wrong
+    fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "incorrect, too high",
+            Verdict::TooLow => "incorrect, too low",
+            Verdict::RateLimited => "not checked, submitted too soon after the previous answer",
+            Verdict::WrongLevel => "not checked, the part is already solved or still locked",
+        })
+    }
+}
+
+/// Parses durations like `1m 23s` from `You have 1m 23s left to wait` or
+/// `please wait 5 minutes before trying again`, in seconds
+fn parse_wait(body: &str) -> Option<u64> {
+    if let Some((_, rest)) = body.split_once("You have ") {
+        let (duration, _) = rest.split_once(" left to wait")?;
+        return duration
+            .split_whitespace()
+            .map(|amount| {
+                let (unit_start, unit) = amount.char_indices().next_back()?;
+                let count = &amount[..unit_start];
+                let seconds = match unit {
+                    'h' => 3600,
+                    'm' => 60,
+                    _ => 1,
+                };
+                count.parse::<u64>().ok().map(|count| count * seconds)
+            })
+            .sum();
+    }
+    let (_, rest) = body.split_once("wait ")?;
+    let (count, unit) = rest.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    unit.starts_with("minute").then_some(count * 60)
+}
+
+/// Determines the verdict and how many seconds to wait before the next
+/// submission from the server's response
+fn parse_response(body: &str) -> Option<(Verdict, Option<u64>)> {
+    let verdict = if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if body.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return None;
+    };
+    Some((verdict, parse_wait(body)))
+}
+
+/// Submits answers to an Advent of Code compatible server
+pub struct Submitter {
+    pub endpoint: String,
+    pub session: String,
+}
+
+impl Submitter {
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> io::Result<(Verdict, Option<u64>)> {
+        let url = format!(
+            "{}/{year}/day/{day}/answer",
+            self.endpoint.trim_end_matches('/')
+        );
+        let failed =
+            |err: ureq::Error| io::Error::other(format!("could not submit to {url}: {err}"));
+        let body = ureq::post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", env!("CARGO_PKG_NAME"))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .map_err(failed)?
+            .body_mut()
+            .read_to_string()
+            .map_err(failed)?;
+        parse_response(&body).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unexpected response from {url}"),
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub submitted_at: u64,
+    /// No answer for this part is accepted before this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+/// Every answer that was submitted, stored as an array of TOML tables:
+///
+/// ```toml
+/// [[attempt]]
+/// year = 2023
+/// day = 1
+/// part = 1
+/// answer = "142"
+/// verdict = "too-low"
+/// submitted_at = 1701406800
+/// retry_after = 1701406860
+/// ```
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, content)
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Checks whether submitting `answer` can possibly be worth it, based on
+    /// the previous attempts for the same part
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        now: u64,
+    ) -> Result<(), String> {
+        let part = part.number();
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part));
+        for attempt in attempts {
+            let previous = &attempt.answer;
+            match attempt.verdict {
+                Verdict::Correct if previous == answer => {
+                    return Err(format!("{answer} is already known to be correct"))
+                }
+                Verdict::Correct => {
+                    return Err(format!(
+                        "the correct answer is already known to be {previous}"
+                    ))
+                }
+                verdict if verdict.is_wrong() && previous == answer => {
+                    return Err(format!("{answer} is already known to be {verdict}"))
+                }
+                Verdict::TooHigh if answer >= previous => {
+                    return Err(format!("{previous} was already too high"))
+                }
+                Verdict::TooLow if answer <= previous => {
+                    return Err(format!("{previous} was already too low"))
+                }
+                _ => (),
+            }
+            if let Some(wait) = attempt.retry_after.filter(|&retry_after| retry_after > now) {
+                return Err(format!("have to wait another {}s", wait - now));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System time is before the Unix epoch")
+        .as_secs()
+}
+
+pub fn submit(args: SubmitArgs) -> bool {
+    let mut history = match History::load(&args.history) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("Could not load {}: {err}", args.history.display());
+            return false;
+        }
+    };
+    let (results, success) = runner::run(&args.selection);
+    let [result] = results.as_slice() else {
+        return false;
+    };
+    let (true, Some(answer)) = (success, result.answer()) else {
+        return false;
+    };
+    let (year, day, part) = (result.year, result.day, result.part);
+    let submitted_at = now();
+    if let Err(reason) = history.check(year, day, part, answer, submitted_at) {
+        eprintln!("Not submitting {answer} for {year} day {day} part {part}, {reason}");
+        return false;
+    }
+    let Some(session) = args.selection.session() else {
+        eprintln!(
+            "Set {}, `session` in {} or save your session cookie in {} to submit answers",
+            input::SESSION_VAR,
+            config::FILE_NAME,
+            input::session_file().unwrap_or_default().display()
+        );
+        return false;
+    };
+
+    let submitter = Submitter {
+        endpoint: args.selection.endpoint().to_string(),
+        session,
+    };
+    let (verdict, wait) = match submitter.submit(year, day, part, answer) {
+        Ok(response) => response,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    println!("{year} day {day} part {part}: {answer} is {verdict}");
+    if let Some(wait) = wait {
+        println!("Wait {wait}s before submitting again");
+    }
+
+    history.record(Attempt {
+        year,
+        day,
+        part: part.number(),
+        answer: answer.clone(),
+        verdict,
+        submitted_at,
+        retry_after: wait.map(|wait| submitted_at + wait),
+    });
+    if let Err(err) = history.save(&args.history) {
+        eprintln!("Could not save {}: {err}", args.history.display());
+        return false;
+    }
+    verdict == Verdict::Correct
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input::test::serve_once;
+    use pretty_assertions::assert_eq;
+
+    fn attempt(answer: u32, verdict: Verdict, retry_after: Option<u64>) -> Attempt {
+        Attempt {
+            year: 2023,
+            day: 1,
+            part: 1,
+            answer: answer.into(),
+            verdict,
+            submitted_at: 100,
+            retry_after,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let cases = [
+            ("<p>That's the right answer! You are one gold star closer.</p>", Some((Verdict::Correct, None))),
+            (
+                "<p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p>",
+                Some((Verdict::TooHigh, Some(60))),
+            ),
+            (
+                "<p>That's not the right answer; your answer is too low. please wait 5 minutes before trying again.</p>",
+                Some((Verdict::TooLow, Some(300))),
+            ),
+            (
+                "<p>That's not the right answer. If you're stuck, make sure you're using the full input data.</p>",
+                Some((Verdict::Incorrect, None)),
+            ),
+            (
+                "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p>",
+                Some((Verdict::RateLimited, Some(83))),
+            ),
+            (
+                "<p>You gave an answer too recently. You have 1μs 2é left to wait.</p>",
+                Some((Verdict::RateLimited, None)),
+            ),
+            (
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+                Some((Verdict::WrongLevel, None)),
+            ),
+            ("<p>Puzzle inputs differ by user.</p>", None),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(parse_response(body), expected, "{body}");
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let history = History {
+            attempts: vec![
+                attempt(50, Verdict::TooLow, None),
+                attempt(90, Verdict::TooHigh, None),
+                attempt(70, Verdict::Incorrect, Some(160)),
+            ],
+        };
+        let check = |answer: u32, now| history.check(2023, 1, Part::One, &answer.into(), now);
+
+        assert_eq!(check(60, 160), Ok(()));
+        assert_eq!(check(60, 150), Err("have to wait another 10s".to_string()));
+        assert_eq!(
+            check(50, 160),
+            Err("50 is already known to be incorrect, too low".to_string())
+        );
+        assert_eq!(check(40, 160), Err("50 was already too low".to_string()));
+        assert_eq!(check(95, 160), Err("90 was already too high".to_string()));
+        assert_eq!(
+            check(70, 160),
+            Err("70 is already known to be incorrect".to_string())
+        );
+        assert_eq!(history.check(2023, 1, Part::Two, &70u32.into(), 0), Ok(()));
+
+        let solved = History {
+            attempts: vec![attempt(60, Verdict::Correct, None)],
+        };
+        assert_eq!(
+            solved.check(2023, 1, Part::One, &61u32.into(), 160),
+            Err("the correct answer is already known to be 60".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (endpoint, server) = serve_once(
+            "200 OK",
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+        );
+        let submitter = Submitter {
+            endpoint,
+            session: "53cr3t".to_string(),
+        };
+        let response = submitter.submit(2023, 7, Part::Two, &Answer::from(5905u32));
+        assert_eq!(response.unwrap(), (Verdict::Correct, None));
+
+        let request = server.join().unwrap();
+        assert!(request
+            .to_lowercase()
+            .starts_with("post /2023/day/7/answer http/1.1\r\n"));
+        assert!(request
+            .to_lowercase()
+            .contains("cookie: session=53cr3t\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=5905"));
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let history = History {
+            attempts: vec![attempt(50, Verdict::TooLow, Some(160))],
+        };
+        let serialized = toml::to_string_pretty(&history).unwrap();
+        assert_eq!(toml::from_str::<History>(&serialized).unwrap(), history);
+    }
+}
@@ -169,7 +169,7 @@ mod test {
 
     #[test]
     fn test_iterate_2d() {
-        let matrix = vec![vec![1, 2], vec![6], vec![8, 9, 10]];
+        let matrix = [vec![1, 2], vec![6], vec![8, 9, 10]];
         let mut matrix_it = matrix.iter_2d();
 
         assert_eq!(matrix_it.next(), Some(&1));
@@ -182,7 +182,7 @@ mod test {
 
     #[test]
     fn test_enumerate() {
-        let matrix = vec![vec![1, 2], vec![6], vec![8, 9, 10]];
+        let matrix = [vec![1, 2], vec![6], vec![8, 9, 10]];
         let mut matrix_it = matrix.iter_2d().enumerate_2d().map(|(pt, _)| pt);
 
         assert_eq!(matrix_it.next(), Some(Point::new(0usize, 0)));
@@ -195,7 +195,7 @@ mod test {
 
     #[test]
     fn test_get_point_wrap() {
-        let matrix = vec![vec![1, 2], vec![4, 5], vec![7, 8]];
+        let matrix = [vec![1, 2], vec![4, 5], vec![7, 8]];
 
         // Normal indices
         assert_eq!(matrix.get_point_wrap(Vector2D::new(0isize, 0)), &1);
@@ -31,7 +31,7 @@ pub struct ListIterator<'a, T> {
 }
 
 impl<T> List<T> {
-    pub fn iter(&self) -> ListIterator<T> {
+    pub fn iter(&self) -> ListIterator<'_, T> {
         ListIterator {
             current_node: &self.head,
         }
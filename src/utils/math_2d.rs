@@ -1,8 +1,11 @@
 use std::{
     fmt::{self, Display},
     ops::{Add, AddAssign, Mul, Sub, SubAssign},
+    str::FromStr,
 };
 
+use crate::parse::ParseError;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Direction {
     North,
@@ -20,14 +23,16 @@ impl Direction {
     ];
 }
 
-impl From<&str> for Direction {
-    fn from(value: &str) -> Self {
-        match value {
-            "U" => Direction::North,
-            "D" => Direction::South,
-            "L" => Direction::West,
-            "R" => Direction::East,
-            _ => unimplemented!(),
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::North),
+            "D" => Ok(Direction::South),
+            "L" => Ok(Direction::West),
+            "R" => Ok(Direction::East),
+            _ => Err(ParseError::new(s, "'U', 'D', 'L' or 'R'")),
         }
     }
 }
@@ -148,6 +153,18 @@ impl<T: Copy + PartialOrd + num::Num> OrdDistance<T> for Vector2D<T> {
     }
 }
 
+impl<T: Copy> Vector2D<T> {
+    /// Column, growing to the east
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    /// Row, growing to the south
+    pub fn y(&self) -> T {
+        self.y
+    }
+}
+
 impl<T: num::Signed> Vector2D<T> {
     pub fn distance(&self, other: &Vector2D<T>) -> T {
         self.x.abs_sub(&other.x) + self.y.abs_sub(&other.y)
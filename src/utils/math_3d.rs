@@ -4,6 +4,8 @@ use std::{
     str::FromStr,
 };
 
+use crate::parse::{self, ParseError};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Vector3<Idx> {
     pub(crate) x: Idx,
@@ -17,6 +19,20 @@ impl<Idx> Vector3<Idx> {
     }
 }
 
+impl<Idx: Copy> Vector3<Idx> {
+    pub fn x(&self) -> Idx {
+        self.x
+    }
+
+    pub fn y(&self) -> Idx {
+        self.y
+    }
+
+    pub fn z(&self) -> Idx {
+        self.z
+    }
+}
+
 impl<Idx> Display for Vector3<Idx>
 where
     Idx: Display,
@@ -31,22 +47,17 @@ impl<Idx> FromStr for Vector3<Idx>
 where
     Idx: FromStr,
 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split(',');
-        let x = parts
-            .next()
-            .and_then(|x| x.trim().parse().ok())
-            .ok_or("Error parsing x value")?;
-        let y = parts
-            .next()
-            .and_then(|y| y.trim().parse().ok())
-            .ok_or("Error parsing y value")?;
-        let z = parts
-            .next()
-            .and_then(|z| z.trim().parse().ok())
-            .ok_or("Error parsing z value")?;
+        let mut next = |name: &str| match parts.next() {
+            Some(part) => parse::number(part.trim()),
+            None => Err(ParseError::new(&s[s.len()..], format!("a {name} value"))),
+        };
+        let x = next("x")?;
+        let y = next("y")?;
+        let z = next("z")?;
         Ok(Self::new(x, y, z))
     }
 }
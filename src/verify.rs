@@ -0,0 +1,225 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answer::Answer,
+    config::{ApplyConfig, Config},
+    output::write_table,
+    registry,
+    runner::{self, Part, PartResult, Selection},
+};
+
+const HEADER: [&str; 6] = ["Year", "Day", "Part", "Expected", "Actual", "Status"];
+
+fn row(result: &PartResult, expected: Option<Answer>, status: &str) -> [String; 6] {
+    [
+        result.year.to_string(),
+        result.day.to_string(),
+        result.part.to_string(),
+        expected.map_or("-".to_string(), |e| e.to_string()),
+        result.answer().map_or("-".to_string(), |a| a.to_string()),
+        status.to_string(),
+    ]
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// File containing the known good answers [default: answers.toml]
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Store the current answers as the known good ones instead of checking them
+    #[arg(long, conflicts_with = "example")]
+    record: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Known good answers, stored as TOML tables per year and day:
+///
+/// ```toml
+/// [2023.day_01]
+/// part1 = "142"
+/// part2 = "281"
+/// ```
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KnownAnswers(BTreeMap<String, BTreeMap<String, DayAnswers>>);
+
+fn day_key(day: u8) -> String {
+    format!("day_{day:02}")
+}
+
+impl KnownAnswers {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, content)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        self.0.get(&year.to_string())?.get(&day_key(day))?.get(part)
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: Part, answer: Answer) {
+        let answers = self
+            .0
+            .entry(year.to_string())
+            .or_default()
+            .entry(day_key(day))
+            .or_default();
+        match part {
+            Part::One => answers.part1 = Some(answer),
+            Part::Two => answers.part2 = Some(answer),
+        }
+    }
+}
+
+impl ApplyConfig for VerifyArgs {
+    fn apply(&mut self, config: &Config) {
+        self.selection.apply(config);
+        self.answers = self.answers.take().or_else(|| config.answers.clone());
+    }
+}
+
+/// Prints `results` next to the answers their samples should produce, and
+/// returns whether all of them match
+pub fn compare_with_samples(results: &[PartResult]) -> bool {
+    let mut success = true;
+    let mut rows = vec![];
+    for result in results {
+        let expected = registry::sample(result.year, result.day, result.part)
+            .and_then(|sample| sample.expected(result.part));
+        let status = match (&expected, result.answer()) {
+            (_, None) => "ERROR",
+            (Some(expected), Some(answer)) if expected == answer => "ok",
+            (Some(_), _) => {
+                success = false;
+                "MISMATCH"
+            }
+            (None, _) => "unknown",
+        };
+        rows.push(row(result, expected, status));
+    }
+    write_table(&mut io::stdout().lock(), HEADER, &rows).expect("Failed to write results");
+    success
+}
+
+pub fn verify(args: VerifyArgs) -> bool {
+    if args.selection.example() {
+        let (results, success) = runner::run(&args.selection);
+        return compare_with_samples(&results) && success;
+    }
+    let answers_path = args.answers.as_deref().unwrap_or(Path::new("answers.toml"));
+    let mut known = match KnownAnswers::load(answers_path) {
+        Ok(known) => known,
+        Err(err) => {
+            eprintln!("Could not load {}: {err}", answers_path.display());
+            return false;
+        }
+    };
+    let (results, mut success) = runner::run(&args.selection);
+
+    let mut rows = vec![];
+    for result in results {
+        let expected = known.get(result.year, result.day, result.part).cloned();
+        let status = match (&expected, result.answer()) {
+            (_, None) => "ERROR",
+            _ if args.record => "recorded",
+            (Some(expected), Some(answer)) if expected == answer => "ok",
+            (Some(_), _) => {
+                success = false;
+                "MISMATCH"
+            }
+            (None, _) => "unknown",
+        };
+        rows.push(row(&result, expected, status));
+        if let (true, Some(answer)) = (args.record, result.answer()) {
+            known.set(result.year, result.day, result.part, answer.clone());
+        }
+    }
+
+    write_table(&mut io::stdout().lock(), HEADER, &rows).expect("Failed to write results");
+
+    if args.record {
+        if let Err(err) = known.save(answers_path) {
+            eprintln!("Could not save {}: {err}", answers_path.display());
+            success = false;
+        }
+    }
+    success
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    const ANSWERS: &str = indoc! {r#"
+        [2023.day_01]
+        part1 = "142"
+        part2 = "281"
+
+        [2023.day_25]
+        part1 = "54"
+    "#};
+
+    #[test]
+    fn test_roundtrip() {
+        let known: KnownAnswers = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(known.get(2023, 1, Part::Two), Some(&Answer::from(281)));
+        assert_eq!(known.get(2023, 25, Part::Two), None);
+        assert_eq!(known.get(2023, 2, Part::One), None);
+        assert_eq!(known.get(2022, 1, Part::One), None);
+        assert_eq!(toml::to_string_pretty(&known).unwrap(), ANSWERS);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut known = KnownAnswers::default();
+        known.set(2023, 25, Part::One, Answer::from(54));
+        known.set(2023, 1, Part::One, Answer::from(142));
+        known.set(2023, 1, Part::Two, Answer::from(281));
+        assert_eq!(known, toml::from_str(ANSWERS).unwrap());
+    }
+
+    #[test]
+    fn test_numeric_answers() {
+        let known: KnownAnswers = toml::from_str("[2023.day_09]\npart1 = -3\n").unwrap();
+        assert_eq!(known.get(2023, 9, Part::One), Some(&Answer::from(-3)));
+    }
+}
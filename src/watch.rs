@@ -0,0 +1,176 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use clap::Args;
+
+use crate::{
+    answer::Answer,
+    input,
+    output::{format_duration, write_table},
+    runner::{self, Part, PartResult, Selection},
+};
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// The inputs of the selected days are watched, use `--input` or
+    /// `--input-dir` to watch alternate inputs instead
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// How often to check the inputs for changes, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+}
+
+impl WatchArgs {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.selection.example() {
+            return Err("samples are part of the source, use `cargo watch` for them".to_string());
+        }
+        if self
+            .paths()
+            .iter()
+            .any(|path| path.as_os_str() == input::STDIN_PATH)
+        {
+            return Err("stdin can't be watched, pass the input as a file".to_string());
+        }
+        if self.selection.has_timeout() {
+            // Every rerun would leave another timed out solution running
+            return Err("watch can't stop solutions that time out, drop `--timeout`".to_string());
+        }
+        Ok(())
+    }
+
+    fn paths(&self) -> Vec<PathBuf> {
+        let days = self.selection.days();
+        let mut paths = days
+            .iter()
+            .map(|&day| self.selection.input_path(day))
+            .collect::<Vec<_>>();
+        paths.dedup();
+        paths
+    }
+}
+
+/// Modification time of each watched file, `None` for files that don't exist
+type Snapshot = Vec<Option<SystemTime>>;
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+type Answers = HashMap<(u16, u8, Part), Answer>;
+
+const HEADER: [&str; 6] = ["Year", "Day", "Part", "Answer", "Time", "Change"];
+
+/// Describes how each result differs from the answer of the previous run
+fn diff(previous: &Answers, results: &[PartResult]) -> Vec<[String; 6]> {
+    results
+        .iter()
+        .map(|result| {
+            let key = (result.year, result.day, result.part);
+            let change = match (previous.get(&key), &result.outcome) {
+                (_, Err(err)) => format!("ERROR: {err}"),
+                (None, Ok(_)) => "new".to_string(),
+                (Some(before), Ok(timed)) if *before == timed.answer => "same".to_string(),
+                (Some(before), Ok(_)) => format!("CHANGED, was {before}"),
+            };
+            [
+                result.year.to_string(),
+                result.day.to_string(),
+                result.part.to_string(),
+                result.answer().map_or("-".to_string(), |a| a.to_string()),
+                result.elapsed().map_or("-".to_string(), format_duration),
+                change,
+            ]
+        })
+        .collect()
+}
+
+/// Runs the selected days whenever one of their inputs changes, until interrupted
+pub fn watch(args: WatchArgs) -> bool {
+    let paths = args.paths();
+    let interval = Duration::from_millis(args.interval);
+    let mut previous = Answers::new();
+    loop {
+        let seen = snapshot(&paths);
+        let (results, _) = runner::run(&args.selection);
+        write_table(&mut io::stdout().lock(), HEADER, &diff(&previous, &results))
+            .expect("Failed to write results");
+        // Keep the last answer of a part that fails, to compare once it's fixed
+        previous.extend(results.iter().filter_map(|result| {
+            let key = (result.year, result.day, result.part);
+            result.answer().map(|answer| (key, answer.clone()))
+        }));
+
+        println!("Watching {} input(s) for changes", paths.len());
+        while snapshot(&paths) == seen {
+            thread::sleep(interval);
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::Timed;
+    use pretty_assertions::assert_eq;
+
+    fn result(part: Part, outcome: Result<u32, &str>) -> PartResult {
+        PartResult {
+            year: 2023,
+            day: 9,
+            part,
+            outcome: outcome
+                .map(|answer| Timed {
+                    answer: answer.into(),
+                    parse_time: Duration::from_micros(10),
+                    solve_time: Duration::from_micros(20),
+                })
+                .map_err(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = [result(Part::One, Ok(114)), result(Part::Two, Ok(2))];
+        let changes = |rows: Vec<[String; 6]>| {
+            rows.into_iter()
+                .map(|[.., change]| change)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(changes(diff(&Answers::new(), &first)), ["new", "new"]);
+
+        let previous = Answers::from([
+            ((2023, 9, Part::One), Answer::from(114)),
+            ((2023, 9, Part::Two), Answer::from(2)),
+        ]);
+        let second = [result(Part::One, Ok(114)), result(Part::Two, Ok(5))];
+        assert_eq!(
+            changes(diff(&previous, &second)),
+            ["same", "CHANGED, was 2"]
+        );
+
+        let third = [result(Part::One, Err("panicked: oops"))];
+        assert_eq!(
+            diff(&previous, &third),
+            [[
+                "2023".to_string(),
+                "9".to_string(),
+                "1".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                "ERROR: panicked: oops".to_string(),
+            ]]
+        );
+    }
+}